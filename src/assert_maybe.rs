@@ -0,0 +1,411 @@
+use core::marker::PhantomData;
+
+use crate::Maybe;
+
+/// Compile-time assertions on the static guarantees of a [`Maybe`](crate::Maybe)-type.
+/// 
+/// Created with [`assert_maybe`]. When used inside a `const`-block in a generic function, a failed assertion gives a
+/// compile-time error for that specific type, instead of a run-time panic.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn f<O>(value: O) -> i32
+/// where
+///     O: Maybe<i32>
+/// {
+///     const { assert_maybe::<O, i32>().msg("`f` needs a value").always_some() };
+/// 
+///     value.unwrap()
+/// }
+/// 
+/// assert_eq!(f(777), 777);
+/// assert_eq!(f([777]), 777);
+/// assert_eq!(f(OptCell::some(777)), 777);
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn f<O>(value: O) -> i32
+/// where
+///     O: Maybe<i32>
+/// {
+///     const { assert_maybe::<O, i32>().msg("`f` needs a value").always_some() };
+/// 
+///     value.unwrap()
+/// }
+/// 
+/// f(());
+/// ```
+pub struct AssertMaybe<M, T>
+where
+    M: Maybe<T> + ?Sized,
+    T: ?Sized
+{
+    msg: Option<&'static str>,
+    marker: PhantomData<(fn() -> *const M, fn() -> *const T)>
+}
+
+/// Starts a compile-time assertion on the static guarantees of the [`Maybe`](crate::Maybe)-type `M`.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// const {
+///     assert_maybe::<i32, i32>().always_some();
+///     assert_maybe::<(), i32>().always_none();
+///     assert_maybe::<Option<i32>, i32>().run_time_managed();
+/// };
+/// ```
+pub const fn assert_maybe<M, T>() -> AssertMaybe<M, T>
+where
+    M: Maybe<T> + ?Sized,
+    T: ?Sized
+{
+    AssertMaybe {
+        msg: None,
+        marker: PhantomData
+    }
+}
+
+impl<M, T> AssertMaybe<M, T>
+where
+    M: Maybe<T> + ?Sized,
+    T: ?Sized
+{
+    /// Replaces the message given if the assertion fails.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust,compile_fail
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<(), i32>().msg("I need a value!").always_some() };
+    /// ```
+    pub const fn msg(self, msg: &'static str) -> Self
+    {
+        Self {
+            msg: Some(msg),
+            marker: PhantomData
+        }
+    }
+
+    /// Asserts that the maybe always contains a value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<[i32; 1], i32>().always_some() };
+    /// ```
+    /// 
+    /// ```rust,compile_fail
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<Option<i32>, i32>().always_some() };
+    /// ```
+    pub const fn always_some(self)
+    {
+        if !M::IS_ALWAYS_SOME
+        {
+            self.fail("the maybe is required to always contain a value")
+        }
+    }
+    /// Asserts that the maybe never contains a value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<[i32; 0], i32>().always_none() };
+    /// ```
+    /// 
+    /// ```rust,compile_fail
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<Option<i32>, i32>().always_none() };
+    /// ```
+    pub const fn always_none(self)
+    {
+        if !M::IS_ALWAYS_NONE
+        {
+            self.fail("the maybe is required to never contain a value")
+        }
+    }
+    /// Asserts that the maybe can't possibly contain a value. This is the same as [`AssertMaybe::always_none`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<(), i32>().never_some() };
+    /// ```
+    /// 
+    /// ```rust,compile_fail
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<i32, i32>().never_some() };
+    /// ```
+    pub const fn never_some(self)
+    {
+        if !M::IS_NEVER_SOME
+        {
+            self.fail("the maybe is required to never contain a value")
+        }
+    }
+    /// Asserts that the maybe can't possibly be empty. This is the same as [`AssertMaybe::always_some`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<i32, i32>().never_none() };
+    /// ```
+    /// 
+    /// ```rust,compile_fail
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<(), i32>().never_none() };
+    /// ```
+    pub const fn never_none(self)
+    {
+        if !M::IS_NEVER_NONE
+        {
+            self.fail("the maybe is required to always contain a value")
+        }
+    }
+    /// Asserts that the maybe is run-time managed, meaning it may or may not contain a value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<Option<i32>, i32>().run_time_managed() };
+    /// ```
+    /// 
+    /// ```rust,compile_fail
+    /// use option_trait::*;
+    /// 
+    /// const { assert_maybe::<[i32; 1], i32>().run_time_managed() };
+    /// ```
+    pub const fn run_time_managed(self)
+    {
+        if !(M::IS_MAYBE_SOME && M::IS_MAYBE_NONE)
+        {
+            self.fail("the maybe is required to be run-time managed")
+        }
+    }
+
+    const fn fail(self, default: &'static str) -> !
+    {
+        match self.msg
+        {
+            Some(msg) => panic!("{}", msg),
+            None => panic!("{}", default)
+        }
+    }
+}
+
+/// Asserts at compile-time that a [`Maybe`](crate::Maybe)-type always contains a value.
+/// 
+/// Expands to a `const`-block, so when used in a generic function, it only fails to compile for the offending types.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn f<O>(value: O) -> i32
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_always_some!(O: Maybe<i32>, "`f` needs a value");
+/// 
+///     value.unwrap()
+/// }
+/// 
+/// assert_eq!(f(777), 777);
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn f<O>(value: O) -> i32
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_always_some!(O: Maybe<i32>, "`f` needs a value");
+/// 
+///     value.unwrap()
+/// }
+/// 
+/// f(None);
+/// ```
+#[macro_export]
+macro_rules! assert_always_some {
+    ($maybe:ty : Maybe<$t:ty> $(, $msg:expr)? $(,)?) => {
+        const { $crate::assert_maybe::<$maybe, $t>()$(.msg($msg))?.always_some() }
+    };
+}
+
+/// Asserts at compile-time that a [`Maybe`](crate::Maybe)-type never contains a value.
+/// 
+/// Expands to a `const`-block, so when used in a generic function, it only fails to compile for the offending types.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_always_none!(O: Maybe<i32>);
+/// }
+/// 
+/// f(());
+/// f([] as [i32; 0]);
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_always_none!(O: Maybe<i32>);
+/// }
+/// 
+/// f(777);
+/// ```
+#[macro_export]
+macro_rules! assert_always_none {
+    ($maybe:ty : Maybe<$t:ty> $(, $msg:expr)? $(,)?) => {
+        const { $crate::assert_maybe::<$maybe, $t>()$(.msg($msg))?.always_none() }
+    };
+}
+
+/// Asserts at compile-time that a [`Maybe`](crate::Maybe)-type can't possibly contain a value.
+/// 
+/// Expands to a `const`-block, so when used in a generic function, it only fails to compile for the offending types.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_never_some!(O: Maybe<i32>, "`f` doesn't take a value");
+/// }
+/// 
+/// f(());
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_never_some!(O: Maybe<i32>, "`f` doesn't take a value");
+/// }
+/// 
+/// f(Some(777));
+/// ```
+#[macro_export]
+macro_rules! assert_never_some {
+    ($maybe:ty : Maybe<$t:ty> $(, $msg:expr)? $(,)?) => {
+        const { $crate::assert_maybe::<$maybe, $t>()$(.msg($msg))?.never_some() }
+    };
+}
+
+/// Asserts at compile-time that a [`Maybe`](crate::Maybe)-type can't possibly be empty.
+/// 
+/// Expands to a `const`-block, so when used in a generic function, it only fails to compile for the offending types.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_never_none!(O: Maybe<i32>);
+/// }
+/// 
+/// f(OptCell::some(777));
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_never_none!(O: Maybe<i32>);
+/// }
+/// 
+/// f(OptCell::<i32, false>::none());
+/// ```
+#[macro_export]
+macro_rules! assert_never_none {
+    ($maybe:ty : Maybe<$t:ty> $(, $msg:expr)? $(,)?) => {
+        const { $crate::assert_maybe::<$maybe, $t>()$(.msg($msg))?.never_none() }
+    };
+}
+/// Asserts at compile-time that a [`Maybe`](crate::Maybe)-type is run-time managed, meaning it may or may not contain a value.
+/// 
+/// Expands to a `const`-block, so when used in a generic function, it only fails to compile for the offending types.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_run_time_managed!(O: Maybe<i32>);
+/// }
+/// 
+/// f(Some(777));
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<i32>
+/// {
+///     assert_run_time_managed!(O: Maybe<i32>);
+/// }
+/// 
+/// f(OptCell::some(777));
+/// ```
+#[macro_export]
+macro_rules! assert_run_time_managed {
+    ($maybe:ty : Maybe<$t:ty> $(, $msg:expr)? $(,)?) => {
+        const { $crate::assert_maybe::<$maybe, $t>()$(.msg($msg))?.run_time_managed() }
+    };
+}
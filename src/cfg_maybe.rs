@@ -0,0 +1,117 @@
+use crate::NotVoid;
+
+/// Expands to the type `T` if the configuration predicate holds, otherwise `()`.
+/// 
+/// This is meant for fields that should only exist when a cargo feature is enabled. Since both `T` and `()` are
+/// [`StaticMaybe`](crate::StaticMaybe)s of `T`, the use sites can go through
+/// [`StaticMaybe::maybe_from_fn`](crate::StaticMaybe::maybe_from_fn),
+/// [`StaticMaybe::with_value`](crate::StaticMaybe::with_value) and
+/// [`StaticMaybe::with_value_mut`](crate::StaticMaybe::with_value_mut) instead, and compile without any `#[cfg]`.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// struct Connection
+/// {
+///     sent: usize,
+///     stats: cfg_maybe!(feature = "stats", u64)
+/// }
+/// 
+/// impl Connection
+/// {
+///     fn new() -> Self
+///     {
+///         Self {
+///             sent: 0,
+///             stats: StaticMaybe::maybe_from_fn(|| 0)
+///         }
+///     }
+/// 
+///     fn send(&mut self, bytes: usize)
+///     {
+///         self.sent += bytes;
+///         StaticMaybe::<u64>::with_value_mut(&mut self.stats, |count| *count += 1);
+///     }
+/// }
+/// 
+/// let mut connection = Connection::new();
+/// connection.send(3);
+/// 
+/// assert_eq!(connection.sent, 3);
+/// assert_eq!(core::mem::size_of_val(&connection.stats), 0);
+/// ```
+#[macro_export]
+macro_rules! cfg_maybe {
+    ($cfg:meta, $t:ty $(,)?) => {
+        <$t as $crate::CfgMaybe<{ cfg!($cfg) }>>::Output
+    };
+}
+
+/// Picks what [`cfg_maybe!`](crate::cfg_maybe) expands to.
+#[doc(hidden)]
+pub trait CfgMaybe<const ENABLED: bool>
+{
+    type Output;
+}
+impl<T> CfgMaybe<true> for T
+{
+    type Output = T;
+}
+impl<T> CfgMaybe<false> for T
+where
+    T: NotVoid
+{
+    type Output = ();
+}
+
+#[cfg(test)]
+mod test
+{
+    use static_assertions::assert_type_eq_all;
+
+    use crate::StaticMaybe;
+
+    struct Counter
+    {
+        enabled: cfg_maybe!(test, u64),
+        disabled: cfg_maybe!(not(test), u64)
+    }
+
+    impl Counter
+    {
+        fn new() -> Self
+        {
+            Self {
+                enabled: StaticMaybe::maybe_from_fn(|| 0),
+                disabled: StaticMaybe::maybe_from_fn(|| 0)
+            }
+        }
+
+        fn tick(&mut self)
+        {
+            StaticMaybe::<u64>::with_value_mut(&mut self.enabled, |count| *count += 1);
+            StaticMaybe::<u64>::with_value_mut(&mut self.disabled, |count| *count += 1);
+        }
+    }
+
+    #[test]
+    fn both_states()
+    {
+        assert_type_eq_all!(cfg_maybe!(test, u64), u64);
+        assert_type_eq_all!(cfg_maybe!(not(test), u64), ());
+
+        let mut counter = Counter::new();
+        counter.tick();
+        counter.tick();
+
+        let mut seen = 0;
+        StaticMaybe::<u64>::with_value(&counter.enabled, |&count| seen += count);
+        StaticMaybe::<u64>::with_value(&counter.disabled, |&count| seen += count);
+
+        assert_eq!(seen, 2);
+        assert_eq!(<cfg_maybe!(not(test), u64) as StaticMaybe<u64>>::COUNT, 0);
+        assert_eq!(core::mem::size_of::<Counter>(), core::mem::size_of::<u64>());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use core::fmt::{Debug, Display, Formatter, Result};
+
+/// Displays the value of a [`Maybe`](crate::Maybe) if it exists, otherwise displays a placeholder string.
+/// 
+/// This is returned by [`Maybe::display_or`](crate::Maybe::display_or).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// assert_eq!(format!("{}", Maybe::<i32>::display_or(&777, "none")), "777");
+/// assert_eq!(format!("{}", Maybe::<i32>::display_or(&(), "none")), "none");
+/// ```
+pub struct DisplayOr<'a, T>
+where
+    T: ?Sized
+{
+    value: Option<&'a T>,
+    none: &'a str
+}
+
+impl<'a, T> DisplayOr<'a, T>
+where
+    T: ?Sized
+{
+    pub(crate) const fn new(value: Option<&'a T>, none: &'a str) -> Self
+    {
+        Self {
+            value,
+            none
+        }
+    }
+}
+
+impl<T> Display for DisplayOr<'_, T>
+where
+    T: Display + ?Sized
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result
+    {
+        match self.value
+        {
+            Some(value) => value.fmt(f),
+            None => f.pad(self.none)
+        }
+    }
+}
+impl<T> Debug for DisplayOr<'_, T>
+where
+    T: Debug + ?Sized
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result
+    {
+        match self.value
+        {
+            Some(value) => value.fmt(f),
+            None => f.pad(self.none)
+        }
+    }
+}
+
+/// Formats any [`Maybe`](crate::Maybe) as if it were an [`Option`](core::option::Option), that is either `Some(..)` or `None`.
+/// 
+/// This is returned by [`Maybe::debug_as_option`](crate::Maybe::debug_as_option).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// assert_eq!(format!("{:?}", Maybe::<i32>::debug_as_option(&777)), "Some(777)");
+/// assert_eq!(format!("{:?}", Maybe::<i32>::debug_as_option(&())), "None");
+/// ```
+pub struct DebugAsOption<'a, T>
+where
+    T: ?Sized
+{
+    value: Option<&'a T>
+}
+
+impl<'a, T> DebugAsOption<'a, T>
+where
+    T: ?Sized
+{
+    pub(crate) const fn new(value: Option<&'a T>) -> Self
+    {
+        Self {
+            value
+        }
+    }
+}
+
+impl<T> Debug for DebugAsOption<'_, T>
+where
+    T: Debug + ?Sized
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result
+    {
+        self.value.fmt(f)
+    }
+}
\ No newline at end of file
@@ -25,6 +25,13 @@
 //! - `T` and `()`
 //!     - Compile-time managed
 //!     - Also implements [`PureStaticMaybe`], [`PureMaybe`] and [`StaticMaybe`]
+//! - [`Poll<T>`](core::task::Poll)
+//!     - Run-time managed
+//!     - [`Poll::Pending`](core::task::Poll::Pending) is treated as the empty maybe
+//! - [`Result<T, E>`](core::result::Result)
+//!     - Run-time managed
+//!     - [`Err`](core::result::Result::Err) is treated as the empty maybe
+//!     - Its pure and mapped forms are [`Option`](core::option::Option)s, so the error is dropped
 //! - `[T; 1]` and `[T; 0]`
 //!     - Compile-time managed
 //!     - Can be managed using constant expressions, but with some difficulty
@@ -34,6 +41,9 @@
 //!     - Can be more easily managed using boolean constant expressions
 //!     - Has const methods
 //!     - Also implements [`StaticMaybe`]
+//! - [`Patch<T>`](crate::Patch) (only if feature `patch` is enabled)
+//!     - Run-time managed
+//!     - Tells an absent value apart from one that's explicitly null
 //!
 //! # Examples
 //!
@@ -66,11 +76,35 @@
 //! f(1, OptCell::some(2));
 //! f(1, OptCell::none());
 //! ```
+//!
+//! If the maybe is compile-time managed, [`maybe_if!`] can be used instead, which never runs the disabled branch and lets the two branches have different types.
+//!
+//! ```rust
+//! use option_trait::*;
+//!
+//! fn g<O>(optional: O) -> <O as StaticMaybe<i32>>::MaybeOr<i32, &'static str>
+//! where
+//!     O: StaticMaybe<i32>,
+//!     <O as StaticMaybe<i32>>::MaybeOr<i32, &'static str>: Sized
+//! {
+//!     // The branches may have different types. Only one of them will ever be run.
+//!     maybe_if!(if let param = optional: StaticMaybe<i32> { param + 1 } else { "nothing" })
+//! }
+//!
+//! assert_eq!(g(1), 2);
+//! assert_eq!(g(()), "nothing");
+//! assert_eq!(g(OptCell::some(1)), 2);
+//! assert_eq!(g(OptCell::none()), "nothing");
+//! ```
 
 moddef::moddef!(
     pub mod {
+        fmt,
         ops,
         opt_cell for cfg(feature = "opt_cell"),
+        opt_cell_if for cfg(feature = "opt_cell"),
+        static_either for cfg(feature = "static_either"),
+        patch for cfg(feature = "patch"),
     },
     flat(pub) mod {
         optional,
@@ -78,12 +112,41 @@ moddef::moddef!(
         pure_maybe,
         static_maybe,
         pure_static_maybe,
+        maybe_eq,
+        maybe_ord,
+        maybe_hash,
+        assert_maybe,
+        require,
+        maybe_future,
+        maybe_mut,
+        maybe_result,
+        maybe_tuple,
+        maybe_iterator,
+        maybe_family,
+        type_eq,
         not_void
     },
+    mod {
+        maybe_if,
+        match_maybe,
+        cfg_maybe
+    }
 );
 
+pub use maybe_if::maybe_if_let;
+#[doc(hidden)]
+pub use match_maybe::{MatchMaybe, MatchRuntime, MatchStatic, RuntimeArms, StaticArms};
+#[doc(hidden)]
+pub use cfg_maybe::CfgMaybe;
+
 #[cfg(feature = "opt_cell")]
 pub use opt_cell::OptCell;
+#[cfg(feature = "opt_cell")]
+pub use opt_cell_if::{ConstPredicate, OptCellIf, SelectOptCell};
+#[cfg(feature = "static_either")]
+pub use static_either::{Either, StaticEither};
+#[cfg(feature = "patch")]
+pub use patch::{Patch, StaticPatch};
 
 #[allow(unused)]
 const unsafe fn transmute_same_size<T, U>(value: T) -> U
@@ -116,20 +179,17 @@ where
 #[allow(unused)]
 const fn assume_same<T, U>(value: T) -> U
 {
-    assert!(is_same_type::<T, U>());
-    unsafe { core::intrinsics::transmute_unchecked::<T, U>(value) }
+    TypeEq::<T, U>::if_same().expect("types are not the same").cast(value)
 }
 #[allow(unused)]
 const fn assume_same_ref<T, U>(value: &T) -> &U
 {
-    assert!(is_same_type::<T, U>());
-    unsafe { core::intrinsics::transmute::<&T, &U>(value) }
+    TypeEq::<T, U>::if_same().expect("types are not the same").cast_ref(value)
 }
 #[allow(unused)]
 const fn assume_same_mut<T, U>(value: &mut T) -> &mut U
 {
-    assert!(is_same_type::<T, U>());
-    unsafe { core::intrinsics::transmute::<&mut T, &mut U>(value) }
+    TypeEq::<T, U>::if_same().expect("types are not the same").cast_mut(value)
 }
 const fn copy_ref<T>(src: &T) -> <T as Copied>::Output
 where
@@ -155,6 +215,34 @@ where
     assert!(is_same_type::<T, &<T as Copied>::Output>() || is_same_type::<T, &mut <T as Copied>::Output>());
     unsafe { (*core::intrinsics::transmute::<&T, &&<T as Copied>::Output>(src)).clone() }
 }
+fn poll_option<T>(poll: core::task::Poll<T>) -> Option<T>
+{
+    match poll
+    {
+        core::task::Poll::Ready(value) => Some(value),
+        core::task::Poll::Pending => None
+    }
+}
+fn option_poll<T>(option: Option<T>) -> core::task::Poll<T>
+{
+    match option
+    {
+        Some(value) => core::task::Poll::Ready(value),
+        None => core::task::Poll::Pending
+    }
+}
+/// Fails to compile whenever [`Maybe::rewrap`] is instantiated for a target that can't hold every state of the source.
+struct Rewrap<Src, Dst, T>(core::marker::PhantomData<Src>, core::marker::PhantomData<Dst>, core::marker::PhantomData<T>);
+impl<Src, Dst, T> Rewrap<Src, Dst, T>
+where
+    Src: Maybe<T>,
+    Dst: Maybe<T>
+{
+    const COMPATIBLE: () = {
+        assert!(!Src::IS_MAYBE_SOME || Dst::IS_MAYBE_SOME, "the target of `rewrap` can't contain a value, but the maybe can");
+        assert!(!Src::IS_MAYBE_NONE || Dst::IS_MAYBE_NONE, "the target of `rewrap` must contain a value, but the maybe can be empty");
+    };
+}
 const fn on_unwrap_empty() -> !
 {
     panic!("called `Maybe::unwrap()` on a `None` value")
@@ -164,9 +252,47 @@ const fn on_unwrap_empty_msg(msg: &str) -> !
     panic!("{}", msg)
 }
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not the same type as `{T}`",
+    label = "expected `{T}`"
+)]
 pub trait Same<T>: private::Same<T> {}
 impl<T, U> Same<T> for U where U: private::Same<T> {}
 
+/// A sealed trait for the maybes that [`Maybe::rewrap`] and [`Maybe::try_rewrap`] can convert into.
+/// 
+/// These are [`Option<T>`](core::option::Option), [`Poll<T>`](core::task::Poll) and every [`StaticMaybe`].
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn into_array<M>(maybe: M) -> Result<[i32; 1], Option<i32>>
+/// where
+///     M: Maybe<i32>
+/// {
+///     maybe.try_rewrap()
+/// }
+/// 
+/// assert_eq!(into_array(Some(777)), Ok([777]));
+/// assert_eq!(into_array(None), Err(None));
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// // A result would have to make up an error.
+/// let result = Maybe::<i32>::try_rewrap::<Result<i32, ()>>(Some(777));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "a maybe of `{T}` can't be rewrapped into `{Self}`",
+    label = "expected `Option<{T}>`, `Poll<{T}>` or a compile-time managed maybe of `{T}`",
+    note = "maybes can only be rewrapped into `Option<{T}>`, `Poll<{T}>` or a `StaticMaybe<{T}>`"
+)]
+pub trait RewrapTarget<T>: Maybe<T> + private::_Rewrap<T> {}
+impl<T, M> RewrapTarget<T> for M where M: Maybe<T> + private::_Rewrap<T> {}
+
 pub trait Copied: private::_Copied
 {
     type Output;
@@ -220,24 +346,253 @@ mod private
         type Output = T;
     }
 
-    use crate::NotVoid;
+    use crate::{Maybe, NotVoid};
+
+    pub trait _ArrayKind<U>
+    {
+        type Output;
+
+        fn into_array(self) -> Self::Output;
+    }
+    impl<U, P> _ArrayKind<U> for P
+    {
+        default type Output = P;
+
+        default fn into_array(self) -> Self::Output
+        {
+            crate::assume_same(self)
+        }
+    }
+    impl<U> _ArrayKind<U> for U
+    {
+        type Output = [U; 1];
+
+        fn into_array(self) -> Self::Output
+        {
+            [self]
+        }
+    }
+    impl<U> _ArrayKind<U> for ()
+    where
+        U: NotVoid
+    {
+        type Output = [U; 0];
+
+        fn into_array(self) -> Self::Output
+        {
+            []
+        }
+    }
+    impl<U> _ArrayKind<U> for Option<U>
+    {
+        type Output = Option<U>;
+
+        fn into_array(self) -> Self::Output
+        {
+            self
+        }
+    }
+
+    pub trait _PollKind<U>
+    {
+        type Output;
+
+        fn into_poll(self) -> Self::Output;
+    }
+    impl<U, P> _PollKind<U> for P
+    {
+        default type Output = P;
+
+        default fn into_poll(self) -> Self::Output
+        {
+            crate::assume_same(self)
+        }
+    }
+    impl<U> _PollKind<U> for U
+    {
+        type Output = U;
+
+        fn into_poll(self) -> Self::Output
+        {
+            self
+        }
+    }
+    impl<U> _PollKind<U> for ()
+    where
+        U: NotVoid
+    {
+        type Output = ();
+
+        fn into_poll(self) -> Self::Output
+        {
+            self
+        }
+    }
+    impl<U> _PollKind<U> for Option<U>
+    {
+        type Output = core::task::Poll<U>;
+
+        fn into_poll(self) -> Self::Output
+        {
+            crate::option_poll(self)
+        }
+    }
+
+    pub trait _Rewrap<T>: Sized
+    {
+        fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>;
+        fn rewrap<M>(maybe: M) -> Self
+        where
+            M: Maybe<T>;
+    }
+    impl<T> _Rewrap<T> for Option<T>
+    {
+        fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>
+        {
+            Ok(option)
+        }
+        fn rewrap<M>(maybe: M) -> Self
+        where
+            M: Maybe<T>
+        {
+            maybe.option()
+        }
+    }
+    impl<T> _Rewrap<T> for core::task::Poll<T>
+    {
+        fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>
+        {
+            Ok(crate::option_poll(option))
+        }
+        fn rewrap<M>(maybe: M) -> Self
+        where
+            M: Maybe<T>
+        {
+            crate::option_poll(maybe.option())
+        }
+    }
+    impl<T> _Rewrap<T> for T
+    {
+        fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>
+        {
+            option.ok_or(None)
+        }
+        fn rewrap<M>(maybe: M) -> Self
+        where
+            M: Maybe<T>
+        {
+            maybe.unwrap()
+        }
+    }
+    impl<T> _Rewrap<T> for ()
+    where
+        T: NotVoid
+    {
+        fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>
+        {
+            match option
+            {
+                None => Ok(()),
+                some => Err(some)
+            }
+        }
+        fn rewrap<M>(_: M) -> Self
+        where
+            M: Maybe<T>
+        {
+
+        }
+    }
+    impl<T> _Rewrap<T> for [T; 1]
+    {
+        fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>
+        {
+            option.map(|value| [value]).ok_or(None)
+        }
+        fn rewrap<M>(maybe: M) -> Self
+        where
+            M: Maybe<T>
+        {
+            [maybe.unwrap()]
+        }
+    }
+    impl<T> _Rewrap<T> for [T; 0]
+    {
+        fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>
+        {
+            match option
+            {
+                None => Ok([]),
+                some => Err(some)
+            }
+        }
+        fn rewrap<M>(_: M) -> Self
+        where
+            M: Maybe<T>
+        {
+            []
+        }
+    }
 
     pub trait Optional {}
     impl<T> Optional for Option<T> {}
 
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is not a pure maybe of `{T}`",
+        label = "expected `{T}`, `()` or `Option<{T}>`",
+        note = "the pure maybes of `{T}` are `{T}`, `()` and `Option<{T}>`"
+    )]
     pub trait PureMaybe<T>
     where
         T: ?Sized
     {
+        fn map_value<F>(self, map: F) -> Self
+        where
+            F: FnOnce(T) -> T,
+            T: Sized,
+            Self: Sized;
+    }
+    impl<T> PureMaybe<T> for Option<T>
+    {
+        fn map_value<F>(self, map: F) -> Self
+        where
+            F: FnOnce(T) -> T
+        {
+            self.map(map)
+        }
+    }
+    impl<T> PureMaybe<T> for T
+    where
+        T: ?Sized
+    {
+        fn map_value<F>(self, map: F) -> Self
+        where
+            F: FnOnce(T) -> T,
+            T: Sized
+        {
+            map(self)
+        }
+    }
+    impl<T> PureMaybe<T> for ()
+    where
+        T: NotVoid + ?Sized
+    {
+        fn map_value<F>(self, _: F) -> Self
+        where
+            F: FnOnce(T) -> T,
+            T: Sized
+        {
+
+        }
     }
-    impl<T> PureMaybe<T> for Option<T> {}
-    impl<T> PureMaybe<T> for T where T: ?Sized {}
-    impl<T> PureMaybe<T> for () where T: NotVoid + ?Sized {}
 }
 
 #[cfg(test)]
 mod test
 {
+    use core::task::Poll;
+
+    use proptest::prelude::*;
     use static_assertions::assert_type_eq_all;
 
     use crate as option_trait;
@@ -258,19 +613,22 @@ mod test
         assert_type_eq_all!(<[&i32; 1] as Maybe<&i32>>::Copied, [i32; 1]);
         assert_type_eq_all!(<[&i32; 0] as Maybe<&i32>>::Copied, [i32; 0]);
 
-        // This is supposed to work...
-        /*assert_type_eq_all!(<Option<i32> as Maybe<i32>>::Copied, Option<i32>);
-        assert_type_eq_all!(<i32 as Maybe<i32>>::Copied, i32);
-        assert_type_eq_all!(<() as Maybe<i32>>::Copied, ());
-        assert_type_eq_all!(<[i32; 1] as Maybe<i32>>::Copied, [i32; 1]);
-        assert_type_eq_all!(<[i32; 0] as Maybe<i32>>::Copied, [i32; 0]);*/
+        let copied: Option<i32> = Maybe::<i32>::copied_ref(&Some(777));
+        assert_eq!(copied, Some(777));
+        let copied: i32 = Maybe::<i32>::copied_ref(&777);
+        assert_eq!(copied, 777);
+        let copied: () = Maybe::<i32>::copied_ref(&());
+        assert_eq!(copied, ());
+        let cloned: [i32; 1] = Maybe::<i32>::cloned_ref(&[777]);
+        assert_eq!(cloned, [777]);
+        let cloned: [i32; 0] = Maybe::<i32>::cloned_ref(&[]);
+        assert_eq!(cloned, []);
 
         let maybe = [777];
         let referenced = Maybe::<i32>::as_ref(&maybe);
 
-        // This is supposed to work...
-        //let copy1 = Maybe::<i32>::copied(&maybe);
-        //assert_eq!(copy1, [777]);
+        let copy1 = Maybe::<i32>::copied_ref(&maybe);
+        assert_eq!(copy1, [777]);
 
         let copy2 = Maybe::<&i32>::copied(&referenced);
         assert_eq!(copy2, [777]);
@@ -291,4 +649,229 @@ mod test
         assert!(option.is_some());
         assert_eq!(*option.unwrap(), 777);
     }
+
+    #[test]
+    fn pinned_impls()
+    {
+        use option_trait::*;
+        use core::pin::{pin, Pin};
+
+        fn check<M>(mut maybe: Pin<&mut M>, expected: Option<i32>)
+        where
+            M: Maybe<i32>
+        {
+            let address = maybe.as_mut().option_pin_mut().map(|value| &*value as *const i32);
+
+            assert_eq!(maybe.as_ref().option_pin_ref().map(|value| &*value as *const i32), address);
+            assert_eq!(Maybe::<Pin<&i32>>::option(maybe.as_ref().as_pin_ref()).map(|value| &*value as *const i32), address);
+            assert_eq!(Maybe::<Pin<&mut i32>>::option(maybe.as_mut().as_pin_mut()).map(|value| &*value as *const i32), address);
+            assert_eq!(maybe.as_ref().option_pin_ref().map(|value| *value), expected);
+
+            if let Some(mut value) = maybe.as_mut().option_pin_mut()
+            {
+                value.set(666)
+            }
+            assert_eq!(maybe.as_ref().option_pin_ref().map(|value| *value), expected.map(|_| 666));
+        }
+
+        check(pin!(777), Some(777));
+        check(pin!(Some(777)), Some(777));
+        check(pin!(None::<i32>), None);
+        check(pin!([777]), Some(777));
+        check(pin!([] as [i32; 0]), None);
+        check(pin!(OptCell::some(777)), Some(777));
+        check(pin!(OptCell::<i32, false>::none()), None);
+        check(pin!(()), None);
+        check(pin!(Ok::<i32, ()>(777)), Some(777));
+        check(pin!(Err::<i32, ()>(())), None);
+        check(pin!(Poll::Ready(777)), Some(777));
+        check(pin!(Poll::Pending::<i32>), None);
+    }
+
+    // The values that have caught bugs before, checked on every run on top of the generated ones.
+    const EDGE_CASES: [i32; 5] = [0, 1, -1, i32::MIN, i32::MAX];
+
+    // Miri is orders of magnitude slower, so it only gets a handful of cases.
+    fn config() -> ProptestConfig
+    {
+        ProptestConfig {
+            cases: if cfg!(miri) {8} else {256},
+            failure_persistence: None,
+            ..ProptestConfig::default()
+        }
+    }
+
+    macro_rules! check_like_option {
+        ($maybe:expr, $option:expr) => {{
+            let option: Option<i32> = $option;
+            let mut maybe = $maybe;
+
+            assert_eq!(Maybe::<i32>::is_some(&maybe), option.is_some());
+            assert_eq!(Maybe::<i32>::option_ref(&maybe), option.as_ref());
+
+            let referenced = Maybe::<i32>::as_ref(&maybe);
+            assert_eq!(Maybe::<i32>::option(Maybe::<&i32>::copied(&referenced)), option);
+            assert_eq!(Maybe::<i32>::option(Maybe::<&i32>::cloned(&referenced)), option);
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::copied_ref(&maybe)), option);
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::cloned_ref(&maybe)), option);
+
+            assert_eq!(Maybe::<&i32>::option(Maybe::<i32>::pure_ref(&maybe)), option.as_ref());
+            assert_eq!(Maybe::<&mut i32>::option(Maybe::<i32>::pure_mut(&mut maybe)).map(|value| *value), option);
+            assert_eq!(Maybe::<core::pin::Pin<&i32>>::option(Maybe::<i32>::pure_pin_ref(core::pin::Pin::new(&maybe))).map(|value| *value), option);
+            assert_eq!(Maybe::<core::pin::Pin<&mut i32>>::option(Maybe::<i32>::pure_pin_mut(core::pin::Pin::new(&mut maybe))).map(|value| *value), option);
+
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::filter(maybe, |value| value % 2 == 0)), option.filter(|value| value % 2 == 0));
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::filter(maybe, |_| true)), option);
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::filter(maybe, |_| false)), None);
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::map_maybe::<i32, _, _>(maybe, Some)), option);
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::map_maybe::<i32, _, _>(maybe, |value| [value])), option);
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::map_maybe::<i32, _, _>(maybe, |_| ())), None);
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::pure(maybe)), option);
+
+            assert_eq!(Maybe::<i32>::rewrap::<Option<i32>>(maybe), option);
+            assert_eq!(Maybe::<i32>::try_rewrap::<Option<i32>>(maybe), Ok(option));
+            assert_eq!(Maybe::<i32>::rewrap::<Poll<i32>>(maybe), crate::option_poll(option));
+            assert_eq!(Maybe::<i32>::try_rewrap::<[i32; 1]>(maybe), option.map(|value| [value]).ok_or(None));
+            assert_eq!(Maybe::<i32>::try_rewrap::<()>(maybe).map_err(|_| ()), if option.is_some() {Err(())} else {Ok(())});
+            assert_eq!(Maybe::<i32>::try_rewrap::<OptCell<i32, true>>(maybe).map(OptCell::option), option.ok_or(None).map(Some));
+        }};
+    }
+
+    fn like_option(value: i32)
+    {
+        check_like_option!(value, Some(value));
+        check_like_option!(Some(value), Some(value));
+        check_like_option!(None::<i32>, None);
+        check_like_option!([value], Some(value));
+        check_like_option!([] as [i32; 0], None);
+        check_like_option!((), None);
+        check_like_option!(OptCell::some(value), Some(value));
+        check_like_option!(OptCell::<i32, false>::none(), None);
+        check_like_option!(Ok::<i32, ()>(value), Some(value));
+        check_like_option!(Err::<i32, ()>(()), None);
+        check_like_option!(Poll::Ready(value), Some(value));
+        check_like_option!(Poll::Pending::<i32>, None);
+    }
+
+    macro_rules! check_ops_like_option {
+        ($lhs:expr, $lhs_option:expr; $other:expr) => {{
+            let other: i32 = $other;
+
+            check_ops_like_option!(@pair $lhs, $lhs_option; other, Some(other));
+            check_ops_like_option!(@pair $lhs, $lhs_option; Some(other), Some(other));
+            check_ops_like_option!(@pair $lhs, $lhs_option; None::<i32>, None);
+            check_ops_like_option!(@pair $lhs, $lhs_option; [other], Some(other));
+            check_ops_like_option!(@pair $lhs, $lhs_option; [] as [i32; 0], None);
+            check_ops_like_option!(@pair $lhs, $lhs_option; (), None);
+            check_ops_like_option!(@pair $lhs, $lhs_option; OptCell::some(other), Some(other));
+            check_ops_like_option!(@pair $lhs, $lhs_option; OptCell::<i32, false>::none(), None);
+            check_ops_like_option!(@pair $lhs, $lhs_option; Poll::Ready(other), Some(other));
+            check_ops_like_option!(@pair $lhs, $lhs_option; Poll::Pending::<i32>, None);
+        }};
+        (@pair $lhs:expr, $lhs_option:expr; $rhs:expr, $rhs_option:expr) => {{
+            let (lhs, rhs): (Option<i32>, Option<i32>) = ($lhs_option, $rhs_option);
+
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::and($lhs, $rhs)), lhs.and(rhs));
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::and_then::<i32, _>($lhs, |_| $rhs)), lhs.and_then(|_| rhs));
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::or($lhs, $rhs)), lhs.or(rhs));
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::or_else($lhs, || $rhs)), lhs.or_else(|| rhs));
+            assert_eq!(Maybe::<i32>::option(Maybe::<i32>::xor($lhs, $rhs)), lhs.xor(rhs));
+        }};
+    }
+
+    fn ops_like_option(value: i32, other: i32)
+    {
+        check_ops_like_option!(value, Some(value); other);
+        check_ops_like_option!(Some(value), Some(value); other);
+        check_ops_like_option!(None::<i32>, None; other);
+        check_ops_like_option!([value], Some(value); other);
+        check_ops_like_option!([] as [i32; 0], None; other);
+        check_ops_like_option!((), None; other);
+        check_ops_like_option!(OptCell::some(value), Some(value); other);
+        check_ops_like_option!(OptCell::<i32, false>::none(), None; other);
+        check_ops_like_option!(Poll::Ready(value), Some(value); other);
+        check_ops_like_option!(Poll::Pending::<i32>, None; other);
+    }
+
+    #[test]
+    fn ops_keep_representation()
+    {
+        let some: [i32; 1] = Maybe::<i32>::or([1], [] as [i32; 0]);
+        let none: [i32; 0] = Maybe::<i32>::and([1], ());
+        let option: Option<i32> = Maybe::<i32>::xor([1], None);
+        assert_eq!((some, none, option), ([1], [], Some(1)));
+
+        let some: OptCell<i32, true> = Maybe::<i32>::or(OptCell::<i32, false>::none(), 2);
+        let none: OptCell<i32, false> = Maybe::<i32>::xor(OptCell::some(1), [2]);
+        let option: Option<i32> = Maybe::<i32>::filter(OptCell::some(1), |_| true);
+        assert_eq!((some.option(), none.option(), option), (Some(2), None, Some(1)));
+
+        let pure: i32 = Maybe::<i32>::or((), [2]);
+        assert_eq!(pure, 2);
+
+        let poll: Poll<i32> = Maybe::<i32>::or(Poll::Pending, None);
+        let pure: i32 = Maybe::<i32>::or(Poll::Pending, 2);
+        let none: () = Maybe::<i32>::and(Poll::Ready(1), ());
+        assert_eq!((poll, pure, none), (Poll::Pending, 2, ()));
+    }
+
+    fn assume_same(mut value: i32)
+    {
+        assert_eq!(crate::assume_same::<i32, i32>(value), value);
+        assert_eq!(crate::assume_same_ref::<i32, i32>(&value), &value);
+        *crate::assume_same_mut::<i32, i32>(&mut value) = 777;
+        assert_eq!(value, 777);
+
+        assert_eq!(crate::copy_ref(&&value), value);
+        assert_eq!(crate::copy_ref(&&mut value), 777);
+        assert_eq!(crate::clone_ref(&&value), value);
+        assert_eq!(crate::clone_ref(&&mut value), 777);
+    }
+
+    proptest! {
+        #![proptest_config(config())]
+
+        #[test]
+        fn prop_like_option(value in any::<i32>())
+        {
+            like_option(value);
+        }
+
+        #[test]
+        fn prop_ops_like_option(value in any::<i32>(), other in any::<i32>())
+        {
+            ops_like_option(value, other);
+        }
+
+        #[test]
+        fn prop_assume_same(value in any::<i32>())
+        {
+            assume_same(value);
+        }
+    }
+
+    #[test]
+    fn edge_cases()
+    {
+        assert!(crate::is_same_type::<i32, i32>());
+        assert!(!crate::is_same_type::<i32, u32>());
+        assert!(!crate::is_same_type::<&i32, i32>());
+
+        for value in EDGE_CASES
+        {
+            like_option(value);
+            assume_same(value);
+            for other in EDGE_CASES
+            {
+                ops_like_option(value, other);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn assume_same_mismatch()
+    {
+        let _ = crate::assume_same::<i32, u32>(777);
+    }
 }
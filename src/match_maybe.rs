@@ -0,0 +1,174 @@
+use core::marker::PhantomData;
+
+use crate::{Maybe, StaticMaybe};
+
+/// Matches on a [`Maybe`](crate::Maybe), with one arm for when it contains a value and one for when it doesn't.
+/// 
+/// If the maybe is a [`StaticMaybe`](crate::StaticMaybe), this is built on
+/// [`StaticMaybe::maybe_or_from_fn`](crate::StaticMaybe::maybe_or_from_fn), so the arm that can't happen is never run,
+/// and the two arms may have different types, just like with [`maybe_if!`](crate::maybe_if). Otherwise, it's a normal
+/// match on the value, and the two arms must have the same type.
+/// 
+/// The arms are evaluated inside closures, so `return`, `break` and `?` apply to the arm itself.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn describe<O>(maybe: O) -> <O as StaticMaybe<i32>>::MaybeOr<String, &'static str>
+/// where
+///     O: StaticMaybe<i32>,
+///     <O as StaticMaybe<i32>>::MaybeOr<String, &'static str>: Sized
+/// {
+///     match_maybe!(maybe: Maybe<i32>,
+///         Some(value) => format!("{value}"),
+///         None => "nothing"
+///     )
+/// }
+/// 
+/// assert_eq!(describe(777), "777");
+/// assert_eq!(describe(()), "nothing");
+/// assert_eq!(describe([777]), "777");
+/// assert_eq!(describe(OptCell::<i32, false>::none()), "nothing");
+/// 
+/// let value = Some(777);
+/// let doubled = match_maybe!(value: Maybe<i32>,
+///     Some(value) => value*2,
+///     None => 0
+/// );
+/// 
+/// assert_eq!(doubled, 1554);
+/// ```
+/// 
+/// The arms of a run-time managed maybe must have the same type.
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// let value = Some(777);
+/// let _ = match_maybe!(value: Maybe<i32>,
+///     Some(value) => value,
+///     None => "nothing"
+/// );
+/// ```
+/// 
+/// Nor can one arm of a run-time managed maybe return a shorter borrow than the other.
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn name_or(value: Option<&'static str>, fallback: &str) -> &'static str
+/// {
+///     match_maybe!(value: Maybe<&'static str>,
+///         Some(value) => value,
+///         None => fallback
+///     )
+/// }
+/// ```
+#[macro_export]
+macro_rules! match_maybe {
+    ($maybe:ident : Maybe<$t:ty>, Some($pat:pat) => $some:expr, None => $none:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{MatchRuntime as _, MatchStatic as _};
+        (&$crate::MatchMaybe::<_, $t>::new(&$maybe)).arms().match_maybe($maybe, |$pat| $some, || $none)
+    }};
+    ($maybe:ident : Maybe<$t:ty>, None => $none:expr, Some($pat:pat) => $some:expr $(,)?) => {
+        $crate::match_maybe!($maybe: Maybe<$t>, Some($pat) => $some, None => $none)
+    };
+    ($maybe:expr, Some($pat:pat) => $some:expr, None => $none:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::{MatchRuntime as _, MatchStatic as _};
+        let maybe = $maybe;
+        (&$crate::MatchMaybe::new(&maybe)).arms().match_maybe(maybe, |$pat| $some, || $none)
+    }};
+    ($maybe:expr, None => $none:expr, Some($pat:pat) => $some:expr $(,)?) => {
+        $crate::match_maybe!($maybe, Some($pat) => $some, None => $none)
+    };
+}
+
+/// The type of a maybe that is being matched on by [`match_maybe!`](crate::match_maybe).
+/// 
+/// The macro calls `arms` on a reference to this, which picks [`MatchStatic`] if the maybe is static, and falls back on
+/// [`MatchRuntime`] otherwise. The maybe itself is only moved into the arms that were picked.
+#[doc(hidden)]
+pub struct MatchMaybe<M, T>(PhantomData<fn() -> (M, T)>);
+
+impl<M, T> MatchMaybe<M, T>
+{
+    pub const fn new(_: &M) -> Self
+    {
+        Self(PhantomData)
+    }
+}
+
+/// Picks the arms of [`match_maybe!`](crate::match_maybe) for static maybes.
+#[doc(hidden)]
+pub trait MatchStatic<M, T>
+{
+    fn arms(&self) -> StaticArms<M, T>
+    {
+        StaticArms(PhantomData)
+    }
+}
+impl<M, T> MatchStatic<M, T> for MatchMaybe<M, T>
+where
+    M: StaticMaybe<T>
+{
+
+}
+
+/// Picks the arms of [`match_maybe!`](crate::match_maybe) for run-time managed maybes.
+#[doc(hidden)]
+pub trait MatchRuntime<M, T>
+{
+    fn arms(&self) -> RuntimeArms<M, T>
+    {
+        RuntimeArms(PhantomData)
+    }
+}
+impl<M, T> MatchRuntime<M, T> for &MatchMaybe<M, T>
+where
+    M: Maybe<T>
+{
+
+}
+
+/// What [`match_maybe!`](crate::match_maybe) expands to for static maybes, where the arms may have different types.
+#[doc(hidden)]
+pub struct StaticArms<M, T>(PhantomData<fn() -> (M, T)>);
+
+impl<M, T> StaticArms<M, T>
+where
+    M: StaticMaybe<T>
+{
+    pub fn match_maybe<FS, FN>(self, maybe: M, some: FS, none: FN) -> M::MaybeOr<FS::Output, FN::Output>
+    where
+        FS: FnOnce<(T,)>,
+        FN: FnOnce<()>,
+        M::MaybeOr<FS::Output, FN::Output>: Sized
+    {
+        M::maybe_or_from_fn(move || some(Maybe::<T>::unwrap(maybe)), none)
+    }
+}
+
+/// What [`match_maybe!`](crate::match_maybe) expands to for run-time managed maybes, where the arms must have the same type.
+#[doc(hidden)]
+pub struct RuntimeArms<M, T>(PhantomData<fn() -> (M, T)>);
+
+impl<M, T> RuntimeArms<M, T>
+where
+    M: Maybe<T>
+{
+    pub fn match_maybe<FS, FN>(self, maybe: M, some: FS, none: FN) -> FS::Output
+    where
+        FS: FnOnce<(T,)>,
+        FN: FnOnce() -> FS::Output
+    {
+        match Maybe::<T>::option(maybe)
+        {
+            Some(value) => some(value),
+            None => none()
+        }
+    }
+}
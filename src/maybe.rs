@@ -1,8 +1,18 @@
-use core::{ops::{Deref, DerefMut}, pin::Pin};
+use core::{fmt::{Debug, Display}, ops::{Deref, DerefMut}, pin::Pin, task::Poll};
 
-use crate::{ops::{MaybeAnd, MaybeAndThen, MaybeFilter, MaybeOr, MaybeXor}, Copied, NotVoid, PureMaybe, StaticMaybe};
+use crate::{fmt::{DebugAsOption, DisplayOr}, ops::{MaybeAnd, MaybeAndThen, MaybeFilter, MaybeOr, MaybeXor}, Copied, NotVoid, PureMaybe, RewrapTarget, StaticMaybe};
 
 /// A trait for maybe-types like [`Option`](core::option::Option), as well as compile-time managed [`Maybe`]-types
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a maybe of `{T}`",
+    label = "expected a maybe of `{T}`",
+    note = "the supported maybes of `{T}` are `{T}`, `()`, `Option<{T}>`, `Result<{T}, E>`, `Poll<{T}>`, `[{T}; 1]` and `[{T}; 0]`"
+)]
+#[cfg_attr(feature = "opt_cell", diagnostic::on_unimplemented(note = "`OptCell<{T}, IS_SOME>` is also a maybe of `{T}`"))]
+#[cfg_attr(feature = "patch", diagnostic::on_unimplemented(note = "`Patch<{T}>` is also a maybe of `{T}`"))]
+#[diagnostic::on_unimplemented(
+    note = "`()` is only the empty maybe of types that implement `NotVoid`, and types that contain `()` don't"
+)]
 pub trait Maybe<T>
 where
     T: ?Sized
@@ -191,9 +201,9 @@ where
     /// let maybe = [777];
     /// let referenced = Maybe::<i32>::as_ref(&maybe);
     /// 
-    /// // This is supposed to work, but the compiler gets confused...
-    /// //let copy1 = Maybe::<i32>::copied(&maybe);
-    /// //assert_eq!(copy1, [777]);
+    /// // For owned values, use `copied_ref` instead.
+    /// let copy1 = Maybe::<i32>::copied_ref(&maybe);
+    /// assert_eq!(copy1, [777]);
     /// 
     /// let copy2 = Maybe::<&i32>::copied(&referenced);
     /// assert_eq!(copy2, [777]);
@@ -202,6 +212,47 @@ where
     where
         T: Copied,
         (): StaticMaybe<<T as Copied>::Output>;
+    /// The maybe returned by [`Maybe::map_maybe`] when mapping the internal value into a maybe of type `M`.
+    /// 
+    /// This is `M` itself if this maybe always contains a value, the same kind of empty maybe if it never does, and an
+    /// [`Option`](core::option::Option) if it's run-time managed.
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// use static_assertions::*;
+    /// 
+    /// assert_type_eq_all!(<Option<i32> as Maybe<i32>>::MappedMaybe<u64, [u64; 1]>, Option<u64>);
+    /// assert_type_eq_all!(<i32 as Maybe<i32>>::MappedMaybe<u64, [u64; 1]>, [u64; 1]);
+    /// assert_type_eq_all!(<() as Maybe<i32>>::MappedMaybe<u64, [u64; 1]>, ());
+    /// assert_type_eq_all!(<[i32; 1] as Maybe<i32>>::MappedMaybe<u64, OptCell<u64, false>>, OptCell<u64, false>);
+    /// assert_type_eq_all!(<[i32; 0] as Maybe<i32>>::MappedMaybe<u64, [u64; 1]>, [u64; 0]);
+    /// ```
+    type MappedMaybe<U, M>: Maybe<U>
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    /// The same kind of maybe as this one, containing a `U`, that is as certain of its contents as the pure maybe `P`.
+    /// 
+    /// This is what the combinators, like [`Maybe::or`], return, so that the output keeps the representation of the
+    /// left-hand side whenever it's able to express the result. Otherwise it's just `P`.
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// use static_assertions::*;
+    /// 
+    /// assert_type_eq_all!(<[i32; 0] as Maybe<i32>>::Kind<u64, u64>, [u64; 1]);
+    /// assert_type_eq_all!(<[i32; 1] as Maybe<i32>>::Kind<u64, ()>, [u64; 0]);
+    /// assert_type_eq_all!(<[i32; 1] as Maybe<i32>>::Kind<u64, Option<u64>>, Option<u64>);
+    /// assert_type_eq_all!(<OptCell<i32, false> as Maybe<i32>>::Kind<u64, u64>, OptCell<u64, true>);
+    /// assert_type_eq_all!(<Option<i32> as Maybe<i32>>::Kind<u64, ()>, ());
+    /// assert_type_eq_all!(<i32 as Maybe<i32>>::Kind<u64, Option<u64>>, Option<u64>);
+    /// assert_type_eq_all!(<core::task::Poll<i32> as Maybe<i32>>::Kind<u64, Option<u64>>, core::task::Poll<u64>);
+    /// assert_type_eq_all!(<core::task::Poll<i32> as Maybe<i32>>::Kind<u64, u64>, u64);
+    /// ```
+    type Kind<U, P>
+    where
+        P: PureMaybe<U>;
     
     /// The same kind of maybe, but with its internal value mutably borrowed.
     /// 
@@ -812,6 +863,33 @@ where
         T: Sized,
         U: StaticMaybe<U>,
         (): StaticMaybe<U>;
+    /// Maps the internal value into another maybe with a mapping function, if it exists.
+    /// 
+    /// As opposed to [`Maybe::and_then`], the maybe returned by `map` may be of any kind, and it's kept as is if this
+    /// maybe always contains a value. The resulting type is [`Maybe::MappedMaybe`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let half = |x: i32| OptCell::<i32, true>::from_fn(|| x/2);
+    /// 
+    /// assert_eq!(Maybe::<i32>::map_maybe::<i32, _, _>(1554, half), OptCell::some(777));
+    /// assert_eq!(Maybe::<i32>::map_maybe::<i32, _, _>([1554], half), OptCell::some(777));
+    /// assert_eq!(Maybe::<i32>::map_maybe::<i32, _, _>(Some(1554), half), Some(777));
+    /// assert_eq!(Maybe::<i32>::map_maybe::<i32, _, _>(None, half), None);
+    /// assert_eq!(Maybe::<i32>::map_maybe::<i32, _, _>((), half), ());
+    /// assert_eq!(Maybe::<i32>::map_maybe::<i32, _, _>(1554, |x| [x/2]), [777]);
+    /// assert_eq!(Maybe::<i32>::map_maybe::<i32, _, _>(Some(1554), |_| [] as [i32; 0]), None);
+    /// ```
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
     /// Maps the internal value with a mapping function, if it exists. Otherwise returns `default`.
     /// 
     /// # Examples
@@ -948,8 +1026,15 @@ where
     /// let b = "Second";
     /// 
     /// assert_eq!(Maybe::<&str>::and(a, b), "Second");
+    /// 
+    /// // The result keeps the representation of the left-hand side.
+    /// let a = [777];
+    /// let b: [i32; 0] = [];
+    /// 
+    /// assert_eq!(Maybe::<i32>::and(a, b), []);
+    /// assert_eq!(Maybe::<i32>::and(a, Some(1)), Some(1));
     /// ```
-    fn and<Rhs>(self, other: Rhs) -> <Self::Pure as MaybeAnd<T, Rhs::Pure>>::Output
+    fn and<Rhs>(self, other: Rhs) -> Self::Kind<T, <Self::Pure as MaybeAnd<T, Rhs::Pure>>::Output>
     where
         Rhs: Maybe<T>,
         Self: Sized,
@@ -959,7 +1044,7 @@ where
         (): StaticMaybe<T>,
         <Self::Pure as MaybeAnd<T, Rhs::Pure>>::Output: Sized
     {
-        MaybeAnd::and(self.pure(), other.pure())
+        Self::from_pure(MaybeAnd::and(self.pure(), other.pure()))
     }
     /// Maps the value into a different maybe if it exists using a flatmap function.
     /// 
@@ -976,7 +1061,7 @@ where
     /// assert_eq!(result2, Some("abcdef"));
     /// ```
     #[doc(alias = "flatmap")]
-    fn and_then<U, F>(self, and_then: F) -> <Self::Pure as MaybeAndThen<T, U, <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure>>::Output
+    fn and_then<U, F>(self, and_then: F) -> Self::Kind<U, <Self::Pure as MaybeAndThen<T, U, <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure>>::Output>
     where
         F: FnOnce<(T,), Output: Maybe<U>>,
         Self: Sized,
@@ -987,7 +1072,7 @@ where
         (): StaticMaybe<T> + StaticMaybe<U>,
         <Self::Pure as MaybeAndThen<T, U, <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure>>::Output: Sized
     {
-        MaybeAndThen::and_then(self.pure(), |x| and_then(x).pure())
+        Self::from_pure(MaybeAndThen::and_then(self.pure(), |x| and_then(x).pure()))
     }
     /// Filters the internal value depending on a predicate.
     /// 
@@ -1005,7 +1090,7 @@ where
     /// assert_eq!(result1, None);
     /// assert_eq!(result2, Some("abcdef"));
     /// ```
-    fn filter<F>(self, predicate: F) -> <Self::Pure as MaybeFilter<T>>::Output
+    fn filter<F>(self, predicate: F) -> Self::Kind<T, <Self::Pure as MaybeFilter<T>>::Output>
     where
         Self: Sized,
         F: Fn(&T) -> bool,
@@ -1013,7 +1098,7 @@ where
         T: StaticMaybe<T> + Sized,
         (): StaticMaybe<T>,
     {
-        MaybeFilter::filter(self.pure(), predicate)
+        Self::from_pure(MaybeFilter::filter(self.pure(), predicate))
     }
     /// Returns the first of the two maybes, if any of them have a value, otherwise returns an empty maybe.
     /// 
@@ -1039,8 +1124,15 @@ where
     /// let b = "Second";
     /// 
     /// assert_eq!(Maybe::<&str>::or(a, b), "First");
+    /// 
+    /// // The result keeps the representation of the left-hand side.
+    /// let a = [777];
+    /// let b: [i32; 0] = [];
+    /// 
+    /// assert_eq!(Maybe::<i32>::or(a, b), [777]);
+    /// assert_eq!(Maybe::<i32>::or(b, a), [777]);
     /// ```
-    fn or<Rhs>(self, other: Rhs) -> <Self::Pure as MaybeOr<T, Rhs::Pure>>::Output
+    fn or<Rhs>(self, other: Rhs) -> Self::Kind<T, <Self::Pure as MaybeOr<T, Rhs::Pure>>::Output>
     where
         Rhs: Maybe<T>,
         Self: Sized,
@@ -1050,7 +1142,7 @@ where
         (): StaticMaybe<T>,
         <Self::Pure as MaybeOr<T, Rhs::Pure>>::Output: Sized
     {
-        MaybeOr::or(self.pure(), other.pure())
+        Self::from_pure(MaybeOr::or(self.pure(), other.pure()))
     }
     /// Returns the first of the two maybes, if any of them have a value, otherwise returns an empty maybe.
     /// 
@@ -1077,7 +1169,7 @@ where
     /// 
     /// assert_eq!(Maybe::<&str>::or_else(a, || b), "First");
     /// ```
-    fn or_else<F>(self, or_else: F) -> <Self::Pure as MaybeOr<T, <<F as FnOnce<()>>::Output as Maybe<T>>::Pure>>::Output
+    fn or_else<F>(self, or_else: F) -> Self::Kind<T, <Self::Pure as MaybeOr<T, <<F as FnOnce<()>>::Output as Maybe<T>>::Pure>>::Output>
     where
         F: FnOnce<(), Output: Maybe<T, Pure: Sized>>,
         Self: Sized,
@@ -1086,7 +1178,7 @@ where
         (): StaticMaybe<T>,
         <Self::Pure as MaybeOr<T, <<F as FnOnce<()>>::Output as Maybe<T>>::Pure>>::Output: Sized
     {
-        MaybeOr::or_else(self.pure(), || or_else().pure())
+        Self::from_pure(MaybeOr::or_else(self.pure(), || or_else().pure()))
     }
     /// Returns the first of the two maybes, if exactly one of them have a value, otherwise returns an empty maybe.
     /// 
@@ -1113,7 +1205,7 @@ where
     /// 
     /// assert_eq!(Maybe::<&str>::xor(a, b), ());
     /// ```
-    fn xor<Rhs>(self, other: Rhs) -> <Self::Pure as MaybeXor<T, Rhs::Pure>>::Output
+    fn xor<Rhs>(self, other: Rhs) -> Self::Kind<T, <Self::Pure as MaybeXor<T, Rhs::Pure>>::Output>
     where
         Rhs: Maybe<T>,
         Self: Sized,
@@ -1123,7 +1215,7 @@ where
         (): StaticMaybe<T>,
         <Self::Pure as MaybeXor<T, Rhs::Pure>>::Output: Sized
     {
-        MaybeXor::xor(self.pure(), other.pure())
+        Self::from_pure(MaybeXor::xor(self.pure(), other.pure()))
     }
     /// Copies the internal value, if it exists, and returns it in a new maybe.
     /// 
@@ -1160,6 +1252,120 @@ where
         T: Copied<Output: Clone>,
         T: Sized,
         (): StaticMaybe<<T as Copied>::Output>;
+    /// Copies the internal value out of a borrowed maybe, if it exists, and returns it in a new maybe of the same kind.
+    /// 
+    /// As opposed to [`Maybe::copied`], this works for any `T: Copy`, not just references.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(Maybe::<i32>::copied_ref(&[777]), [777]);
+    /// assert_eq!(Maybe::<i32>::copied_ref(&Some(777)), Some(777));
+    /// assert_eq!(Maybe::<i32>::copied_ref(&777), 777);
+    /// assert_eq!(Maybe::<i32>::copied_ref(&()), ());
+    /// assert_eq!(Maybe::<i32>::copied_ref(&OptCell::some(777)), OptCell::some(777));
+    /// ```
+    fn copied_ref<'a>(&'a self) -> Self::Mapped<T>
+    where
+        T: Copy + 'a,
+        (): StaticMaybe<T>,
+        Self::AsRef<'a>: Maybe<&'a T, Mapped<T> = Self::Mapped<T>>
+    {
+        Maybe::<&T>::map(self.as_ref(), |value| *value)
+    }
+    /// Clones the internal value out of a borrowed maybe, if it exists, and returns it in a new maybe of the same kind.
+    /// 
+    /// As opposed to [`Maybe::cloned`], this works for any `T: Clone`, not just references.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let maybe = [String::from("777")];
+    /// 
+    /// let cloned = Maybe::<String>::cloned_ref(&maybe);
+    /// 
+    /// assert_eq!(cloned, maybe);
+    /// assert_eq!(Maybe::<String>::cloned_ref(&()), ());
+    /// ```
+    fn cloned_ref<'a>(&'a self) -> Self::Mapped<T>
+    where
+        T: Clone + 'a,
+        (): StaticMaybe<T>,
+        Self::AsRef<'a>: Maybe<&'a T, Mapped<T> = Self::Mapped<T>>
+    {
+        Maybe::<&T>::map(self.as_ref(), T::clone)
+    }
+    /// Converts this maybe into another representation, `M`, with compatible static guarantees.
+    /// 
+    /// If this maybe can contain a value, so must `M`, and if this maybe can be empty, so must `M`. Otherwise this fails to
+    /// compile. So a maybe that's never empty can become any maybe that can hold its value, but a run-time managed maybe
+    /// can only become an [`Option`](core::option::Option) or a [`Poll`](core::task::Poll). See [`Maybe::try_rewrap`] for
+    /// narrowing conversions.
+    /// 
+    /// `M` must be an [`Option`](core::option::Option), a [`Poll`](core::task::Poll) or a [`StaticMaybe`](crate::StaticMaybe),
+    /// as told by [`RewrapTarget`](crate::RewrapTarget).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let maybe = Maybe::<i32>::rewrap::<OptCell<i32, true>>([777]);
+    /// let empty = Maybe::<i32>::rewrap::<[i32; 0]>(());
+    /// let option = Maybe::<i32>::rewrap::<Option<i32>>(777);
+    /// 
+    /// assert_eq!(maybe, OptCell::some(777));
+    /// assert_eq!(empty, []);
+    /// assert_eq!(option, Some(777));
+    /// ```
+    /// 
+    /// ```rust,compile_fail
+    /// use option_trait::*;
+    /// 
+    /// // An option may be empty, which an array of length one can't.
+    /// let maybe = Maybe::<i32>::rewrap::<[i32; 1]>(Some(777));
+    /// ```
+    fn rewrap<M>(self) -> M
+    where
+        Self: Sized,
+        M: RewrapTarget<T>,
+        T: Sized
+    {
+        let () = crate::Rewrap::<Self, M, T>::COMPATIBLE;
+        crate::private::_Rewrap::<T>::rewrap(self)
+    }
+    /// Tries to convert this maybe into another representation, `M`, checking at run-time that it fits.
+    /// 
+    /// If it doesn't, the contents of the maybe is returned as an [`Option`](core::option::Option) instead.
+    /// 
+    /// `M` must be an [`Option`](core::option::Option), a [`Poll`](core::task::Poll) or a [`StaticMaybe`](crate::StaticMaybe),
+    /// as told by [`RewrapTarget`](crate::RewrapTarget).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let maybe = Maybe::<i32>::try_rewrap::<[i32; 1]>(Some(777));
+    /// let empty = Maybe::<i32>::try_rewrap::<[i32; 1]>(None);
+    /// let dropped = Maybe::<i32>::try_rewrap::<()>(777);
+    /// 
+    /// assert_eq!(maybe, Ok([777]));
+    /// assert_eq!(empty, Err(None));
+    /// assert_eq!(dropped, Err(Some(777)));
+    /// ```
+    fn try_rewrap<M>(self) -> Result<M, Option<T>>
+    where
+        Self: Sized,
+        M: RewrapTarget<T>,
+        T: Sized
+    {
+        crate::private::_Rewrap::<T>::try_rewrap(self.option())
+    }
 
     /// Converts this maybe into an [`Option`](core::option::Option).
     /// 
@@ -1249,6 +1455,45 @@ where
     /// ```
     fn option_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>>;
 
+    /// Returns a displayable adapter that shows the internal value if it exists, otherwise shows `none`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let maybe = [777];
+    /// let empty = [];
+    /// 
+    /// assert_eq!(format!("{}", Maybe::<i32>::display_or(&maybe, "nothing")), "777");
+    /// assert_eq!(format!("{}", Maybe::<i32>::display_or(&empty, "nothing")), "nothing");
+    /// ```
+    fn display_or<'a>(&'a self, none: &'a str) -> DisplayOr<'a, T>
+    where
+        T: Display
+    {
+        DisplayOr::new(self.option_ref(), none)
+    }
+    /// Returns a debuggable adapter that formats the maybe like an [`Option`](core::option::Option), regardless of its representation.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(format!("{:?}", Maybe::<i32>::debug_as_option(&777)), "Some(777)");
+    /// assert_eq!(format!("{:?}", Maybe::<i32>::debug_as_option(&[777])), "Some(777)");
+    /// assert_eq!(format!("{:?}", Maybe::<i32>::debug_as_option(&OptCell::some(777))), "Some(777)");
+    /// assert_eq!(format!("{:?}", Maybe::<i32>::debug_as_option(&())), "None");
+    /// assert_eq!(format!("{:?}", Maybe::<i32>::debug_as_option(&([] as [i32; 0]))), "None");
+    /// ```
+    fn debug_as_option(&self) -> DebugAsOption<'_, T>
+    where
+        T: Debug
+    {
+        DebugAsOption::new(self.option_ref())
+    }
+
     /// Converts the maybe into a pure maybe.
     /// 
     /// # Examples
@@ -1346,6 +1591,24 @@ where
     fn pure_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::PurePinMut<'a>
     where
         T: 'a;
+    /// Converts a pure maybe into the same kind of maybe as this one, as described by [`Maybe::Kind`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let maybe = <[i32; 0] as Maybe<i32>>::from_pure::<u64, _>(777);
+    /// let empty = <[i32; 1] as Maybe<i32>>::from_pure::<u64, _>(());
+    /// let option = <[i32; 1] as Maybe<i32>>::from_pure::<u64, _>(Some(777));
+    /// 
+    /// assert_eq!(maybe, [777]);
+    /// assert_eq!(empty, []);
+    /// assert_eq!(option, Some(777));
+    /// ```
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>;
 }
 impl<T> /*const*/ Maybe<T> for T
 where
@@ -1383,6 +1646,14 @@ where
     where
         T: Copied,
         (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = M
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = P
+    where
+        P: PureMaybe<U>;
 
     fn is_some(&self) -> bool
     {
@@ -1544,6 +1815,16 @@ where
     {
         map(self)
     }
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        map(self)
+    }
     fn map_or<U, F>(self, _: U, map: F) -> U
     where
         F: FnOnce(T) -> U,
@@ -1655,6 +1936,12 @@ where
     {
         self
     }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        pure
+    }
 }
 impl<T> /*const*/ Maybe<T> for ()
 where
@@ -1689,6 +1976,14 @@ where
     where
         T: Copied,
         (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = ()
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = P
+    where
+        P: PureMaybe<U>;
 
     fn is_some(&self) -> bool
     {
@@ -1845,6 +2140,16 @@ where
         (): StaticMaybe<U>
     {
         
+    }
+    fn map_maybe<U, M, F>(self, _: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        
     }
     fn map_or<U, F>(self, default: U, _: F) -> U
     where
@@ -1952,6 +2257,12 @@ where
     {
 
     }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        pure
+    }
 }
 impl<T> /*const*/ Maybe<T> for Option<T>
 {
@@ -1987,6 +2298,14 @@ impl<T> /*const*/ Maybe<T> for Option<T>
     where
         T: Copied,
         (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = Option<U>
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = P
+    where
+        P: PureMaybe<U>;
 
     fn is_some(&self) -> bool
     {
@@ -2144,6 +2463,16 @@ impl<T> /*const*/ Maybe<T> for Option<T>
     {
         self.map(map)
     }
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        self.and_then(|value| map(value).option())
+    }
     fn map_or<U, F>(self, default: U, map: F) -> U
     where
         F: FnOnce(T) -> U,
@@ -2254,13 +2583,19 @@ impl<T> /*const*/ Maybe<T> for Option<T>
     {
         self.as_pin_mut()
     }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        pure
+    }
 }
-impl<T> /*const*/ Maybe<T> for [T; 0]
+impl<T, E> /*const*/ Maybe<T> for Result<T, E>
 {
-    const IS_MAYBE_SOME: bool = false;
+    const IS_MAYBE_SOME: bool = true;
     const IS_MAYBE_NONE: bool = true;
 
-    type Pure = <() as Maybe<T>>::Pure
+    type Pure = Option<T>
     where
         T: StaticMaybe<T>,
         (): StaticMaybe<T>;
@@ -2281,7 +2616,7 @@ impl<T> /*const*/ Maybe<T> for [T; 0]
         Self: 'a,
         T: 'a;
 
-    type Mapped<U> = [U; 0]
+    type Mapped<U> = Option<U>
     where
         U: StaticMaybe<U>,
         (): StaticMaybe<U>;
@@ -2289,246 +2624,975 @@ impl<T> /*const*/ Maybe<T> for [T; 0]
     where
         T: Copied,
         (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = Option<U>
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = P
+    where
+        P: PureMaybe<U>;
 
     fn is_some(&self) -> bool
     {
-        false
+        self.is_ok()
     }
     fn is_none(&self) -> bool
     {
-        true
+        self.is_err()
     }
     fn as_ref<'a>(&'a self) -> Self::AsRef<'a>
     where
         T: 'a
     {
-        []
+        self.as_ref().ok()
     }
     fn as_mut<'a>(&'a mut self) -> Self::AsMut<'a>
     where
         T: 'a
     {
-        []
+        self.as_mut().ok()
     }
     fn as_pin_ref<'a>(self: Pin<&'a Self>) -> Self::AsPinRef<'a>
     where
         T: 'a
     {
-        []
+        self.get_ref()
+            .as_ref()
+            .ok()
+            .map(|value| unsafe {
+                Pin::new_unchecked(value)
+            })
     }
     fn as_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::AsPinMut<'a>
     where
         T: 'a
     {
-        []
+        unsafe {
+            self.get_unchecked_mut()
+                .as_mut()
+                .ok()
+                .map(|value| Pin::new_unchecked(value))
+        }
     }
     fn as_slice(&self) -> &[T]
     where
         T: Sized
     {
-        &[]
+        match self
+        {
+            Ok(value) => core::slice::from_ref(value),
+            Err(_) => &[]
+        }
     }
     fn as_mut_slice(&mut self) -> &mut [T]
     where
         T: Sized
     {
-        &mut []
+        match self
+        {
+            Ok(value) => core::slice::from_mut(value),
+            Err(_) => &mut []
+        }
     }
     fn expect(self, msg: &str) -> T
     where
         T: Sized
     {
-        crate::on_unwrap_empty_msg(msg)
+        match self
+        {
+            Ok(value) => value,
+            Err(_) => crate::on_unwrap_empty_msg(msg)
+        }
     }
     fn unwrap(self) -> T
     where
         T: Sized
     {
-        crate::on_unwrap_empty()
+        match self
+        {
+            Ok(value) => value,
+            Err(_) => crate::on_unwrap_empty()
+        }
     }
     fn unwrap_ref(&self) -> &T
     {
-        crate::on_unwrap_empty()
+        Maybe::<&T>::unwrap(Maybe::<T>::as_ref(self))
     }
     fn unwrap_mut(&mut self) -> &mut T
     {
-        crate::on_unwrap_empty()
+        Maybe::<&mut T>::unwrap(Maybe::<T>::as_mut(self))
     }
     fn unwrap_pin_ref<'a>(self: Pin<&'a Self>) -> Pin<&'a T>
     where
         T: 'a
     {
-        crate::on_unwrap_empty()
+        Maybe::<Pin<&T>>::unwrap(Maybe::<T>::as_pin_ref(self))
     }
     fn unwrap_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut T>
     where
         T: 'a
     {
-        crate::on_unwrap_empty()
+        Maybe::<Pin<&mut T>>::unwrap(Maybe::<T>::as_pin_mut(self))
     }
     fn unwrap_or(self, default: T) -> T
     where
         T: Sized
     {
-        default
+        self.unwrap_or(default)
     }
     fn unwrap_ref_or<'a>(&'a self, default: &'a T) -> &'a T
     where
         T: 'a
     {
-        default
+        self.as_ref().unwrap_or(default)
     }
     fn unwrap_mut_or<'a>(&'a mut self, default: &'a mut T) -> &'a mut T
     where
         T: 'a
     {
-        default
+        self.as_mut().unwrap_or(default)
     }
     fn unwrap_pin_ref_or<'a>(self: Pin<&'a Self>, default: Pin<&'a T>) -> Pin<&'a T>
     where
         T: 'a
     {
-        default
+        Maybe::<T>::as_pin_ref(self).unwrap_or(default)
     }
     fn unwrap_pin_mut_or<'a>(self: Pin<&'a mut Self>, default: Pin<&'a mut T>) -> Pin<&'a mut T>
     where
         T: 'a
     {
-        default
+        Maybe::<T>::as_pin_mut(self).unwrap_or(default)
     }
     fn unwrap_or_else<F>(self, default: F) -> T
     where
         F: FnOnce() -> T,
         T: Sized
     {
-        default()
+        self.unwrap_or_else(|_| default())
     }
     fn unwrap_ref_or_else<'a, F>(&'a self, default: F) -> &'a T
     where
         F: FnOnce() -> &'a T,
         T: 'a
     {
-        default()
+        self.as_ref().unwrap_or_else(|_| default())
     }
     fn unwrap_mut_or_else<'a, F>(&'a mut self, default: F) -> &'a mut T
     where
         F: FnOnce() -> &'a mut T,
         T: 'a
     {
-        default()
+        self.as_mut().unwrap_or_else(|_| default())
     }
     fn unwrap_pin_ref_or_else<'a, F>(self: Pin<&'a Self>, default: F) -> Pin<&'a T>
     where
         F: FnOnce() -> Pin<&'a T>,
         T: 'a
     {
-        default()
+        Maybe::<T>::as_pin_ref(self).unwrap_or_else(default)
     }
     fn unwrap_pin_mut_or_else<'a, F>(self: Pin<&'a mut Self>, default: F) -> Pin<&'a mut T>
     where
         F: FnOnce() -> Pin<&'a mut T>,
         T: 'a
     {
-        default()
+        Maybe::<T>::as_pin_mut(self).unwrap_or_else(default)
     }
     fn unwrap_or_default(self) -> T
     where
         T: Sized + Default
     {
-        T::default()
+        self.unwrap_or_default()
     }
-    fn map<U, F>(self, _: F) -> Self::Mapped<U>
+    fn map<U, F>(self, map: F) -> Self::Mapped<U>
     where
         F: FnOnce(T) -> U,
         T: Sized,
         U: StaticMaybe<U>,
         (): StaticMaybe<U>
     {
-        []
+        self.ok().map(map)
     }
-    fn map_or<U, F>(self, default: U, _: F) -> U
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        self.ok()
+            .and_then(|value| map(value).option())
+    }
+    fn map_or<U, F>(self, default: U, map: F) -> U
     where
         F: FnOnce(T) -> U,
         T: Sized
     {
-        default
+        self.map_or(default, map)
     }
-    fn map_or_else<U, D, F>(self, default: D, _: F) -> U
+    fn map_or_else<U, D, F>(self, default: D, map: F) -> U
     where
         D: FnOnce() -> U,
         F: FnOnce(T) -> U,
         T: Sized
     {
-        default()
+        self.map_or_else(|_| default(), map)
     }
-    fn ok_or<E>(self, error: E) -> Result<T, E>
+    fn ok_or<F>(self, error: F) -> Result<T, F>
     where
         T: Sized
     {
-        Err(error)
+        self.ok().ok_or(error)
     }
-    fn ok_or_else<E, F>(self, error: F) -> Result<T, E>
+    fn ok_or_else<F, O>(self, error: O) -> Result<T, F>
     where
-        F: FnOnce() -> E,
+        O: FnOnce() -> F,
         T: Sized
     {
-        Err(error())
+        self.ok().ok_or_else(error)
     }
     fn as_deref<'a>(&'a self) -> Self::AsDeref<'a>
     where
         T: Deref + 'a
     {
-        []
+        self.as_deref().ok()
     }
     fn as_deref_mut<'a>(&'a mut self) -> Self::AsDerefMut<'a>
     where
         T: DerefMut + 'a
     {
-        []
+        self.as_deref_mut().ok()
     }
     fn copied(&self) -> Self::Copied
     where
         T: Copied<Output: Copy>,
         (): StaticMaybe<<T as Copied>::Output>
     {
-        []
+        self.as_ref()
+            .ok()
+            .map(crate::copy_ref)
     }
     fn cloned(&self) -> Self::Copied
     where
         T: Copied<Output: Clone>,
+        T: Sized,
         (): StaticMaybe<<T as Copied>::Output>
     {
-        []
+        self.as_ref()
+            .ok()
+            .map(crate::clone_ref)
     }
 
     fn option(self) -> Option<T>
-    where
-        T: Sized
     {
-        None
+        self.ok()
     }
     fn option_ref(&self) -> Option<&T>
     {
-        None
+        self.as_ref().ok()
     }
     fn option_mut(&mut self) -> Option<&mut T>
     {
-        None
+        self.as_mut().ok()
     }
     fn option_pin_ref(self: Pin<&Self>) -> Option<Pin<&T>>
     {
-        None
+        Maybe::<T>::as_pin_ref(self)
     }
     fn option_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>>
     {
-        None
+        Maybe::<T>::as_pin_mut(self)
     }
 
     fn pure(self) -> Self::Pure
     where
         T: StaticMaybe<T>,
-        (): StaticMaybe<T>,
-        Self::Pure: Sized
+        (): StaticMaybe<T>
+    {
+        self.ok()
+    }
+    fn pure_ref<'a>(&'a self) -> Self::PureRef<'a>
+    where
+        T: 'a
+    {
+        self.as_ref().ok()
+    }
+    fn pure_mut<'a>(&'a mut self) -> Self::PureMut<'a>
+    where
+        T: 'a
+    {
+        self.as_mut().ok()
+    }
+    fn pure_pin_ref<'a>(self: Pin<&'a Self>) -> Self::PurePinRef<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::as_pin_ref(self)
+    }
+    fn pure_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::PurePinMut<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::as_pin_mut(self)
+    }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        pure
+    }
+}
+impl<T> /*const*/ Maybe<T> for Poll<T>
+{
+    const IS_MAYBE_SOME: bool = true;
+    const IS_MAYBE_NONE: bool = true;
+
+    type Pure = Option<T>
+    where
+        T: StaticMaybe<T>,
+        (): StaticMaybe<T>;
+    type PureRef<'a> = <Self::AsRef<'a> as Maybe<&'a T>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PureMut<'a> = <Self::AsMut<'a> as Maybe<&'a mut T>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PurePinRef<'a> = <Self::AsPinRef<'a> as Maybe<Pin<&'a T>>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PurePinMut<'a> = <Self::AsPinMut<'a> as Maybe<Pin<&'a mut T>>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+
+    type Mapped<U> = Poll<U>
+    where
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Copied = Self::Mapped<<T as Copied>::Output>
+    where
+        T: Copied,
+        (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = Poll<U>
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = <P as crate::private::_PollKind<U>>::Output
+    where
+        P: PureMaybe<U>;
+
+    fn is_some(&self) -> bool
+    {
+        self.is_ready()
+    }
+    fn is_none(&self) -> bool
+    {
+        self.is_pending()
+    }
+    fn as_ref<'a>(&'a self) -> Self::AsRef<'a>
+    where
+        T: 'a
+    {
+        crate::option_poll(Maybe::<T>::option_ref(self))
+    }
+    fn as_mut<'a>(&'a mut self) -> Self::AsMut<'a>
+    where
+        T: 'a
+    {
+        crate::option_poll(Maybe::<T>::option_mut(self))
+    }
+    fn as_pin_ref<'a>(self: Pin<&'a Self>) -> Self::AsPinRef<'a>
+    where
+        T: 'a
+    {
+        crate::option_poll(Maybe::<T>::option_pin_ref(self))
+    }
+    fn as_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::AsPinMut<'a>
+    where
+        T: 'a
+    {
+        crate::option_poll(Maybe::<T>::option_pin_mut(self))
+    }
+    fn as_slice(&self) -> &[T]
+    where
+        T: Sized
+    {
+        match self
+        {
+            Poll::Ready(value) => core::slice::from_ref(value),
+            Poll::Pending => &[]
+        }
+    }
+    fn as_mut_slice(&mut self) -> &mut [T]
+    where
+        T: Sized
+    {
+        match self
+        {
+            Poll::Ready(value) => core::slice::from_mut(value),
+            Poll::Pending => &mut []
+        }
+    }
+    fn expect(self, msg: &str) -> T
+    where
+        T: Sized
+    {
+        match self
+        {
+            Poll::Ready(value) => value,
+            Poll::Pending => crate::on_unwrap_empty_msg(msg)
+        }
+    }
+    fn unwrap(self) -> T
+    where
+        T: Sized
+    {
+        match self
+        {
+            Poll::Ready(value) => value,
+            Poll::Pending => crate::on_unwrap_empty()
+        }
+    }
+    fn unwrap_ref(&self) -> &T
+    {
+        Maybe::<&T>::unwrap(Maybe::<T>::as_ref(self))
+    }
+    fn unwrap_mut(&mut self) -> &mut T
+    {
+        Maybe::<&mut T>::unwrap(Maybe::<T>::as_mut(self))
+    }
+    fn unwrap_pin_ref<'a>(self: Pin<&'a Self>) -> Pin<&'a T>
+    where
+        T: 'a
+    {
+        Maybe::<Pin<&T>>::unwrap(Maybe::<T>::as_pin_ref(self))
+    }
+    fn unwrap_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut T>
+    where
+        T: 'a
+    {
+        Maybe::<Pin<&mut T>>::unwrap(Maybe::<T>::as_pin_mut(self))
+    }
+    fn unwrap_or(self, default: T) -> T
+    where
+        T: Sized
+    {
+        crate::poll_option(self).unwrap_or(default)
+    }
+    fn unwrap_ref_or<'a>(&'a self, default: &'a T) -> &'a T
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_ref(self).unwrap_or(default)
+    }
+    fn unwrap_mut_or<'a>(&'a mut self, default: &'a mut T) -> &'a mut T
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_mut(self).unwrap_or(default)
+    }
+    fn unwrap_pin_ref_or<'a>(self: Pin<&'a Self>, default: Pin<&'a T>) -> Pin<&'a T>
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_pin_ref(self).unwrap_or(default)
+    }
+    fn unwrap_pin_mut_or<'a>(self: Pin<&'a mut Self>, default: Pin<&'a mut T>) -> Pin<&'a mut T>
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_pin_mut(self).unwrap_or(default)
+    }
+    fn unwrap_or_else<F>(self, default: F) -> T
+    where
+        F: FnOnce() -> T,
+        T: Sized
+    {
+        crate::poll_option(self).unwrap_or_else(default)
+    }
+    fn unwrap_ref_or_else<'a, F>(&'a self, default: F) -> &'a T
+    where
+        F: FnOnce() -> &'a T,
+        T: 'a
+    {
+        Maybe::<T>::option_ref(self).unwrap_or_else(default)
+    }
+    fn unwrap_mut_or_else<'a, F>(&'a mut self, default: F) -> &'a mut T
+    where
+        F: FnOnce() -> &'a mut T,
+        T: 'a
+    {
+        Maybe::<T>::option_mut(self).unwrap_or_else(default)
+    }
+    fn unwrap_pin_ref_or_else<'a, F>(self: Pin<&'a Self>, default: F) -> Pin<&'a T>
+    where
+        F: FnOnce() -> Pin<&'a T>,
+        T: 'a
+    {
+        Maybe::<T>::option_pin_ref(self).unwrap_or_else(default)
+    }
+    fn unwrap_pin_mut_or_else<'a, F>(self: Pin<&'a mut Self>, default: F) -> Pin<&'a mut T>
+    where
+        F: FnOnce() -> Pin<&'a mut T>,
+        T: 'a
+    {
+        Maybe::<T>::option_pin_mut(self).unwrap_or_else(default)
+    }
+    fn unwrap_or_default(self) -> T
+    where
+        T: Sized + Default
+    {
+        crate::poll_option(self).unwrap_or_default()
+    }
+    fn map<U, F>(self, map: F) -> Self::Mapped<U>
+    where
+        F: FnOnce(T) -> U,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        self.map(map)
+    }
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        crate::option_poll(crate::poll_option(self).and_then(|value| map(value).option()))
+    }
+    fn map_or<U, F>(self, default: U, map: F) -> U
+    where
+        F: FnOnce(T) -> U,
+        T: Sized
+    {
+        crate::poll_option(self).map_or(default, map)
+    }
+    fn map_or_else<U, D, F>(self, default: D, map: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(T) -> U,
+        T: Sized
+    {
+        crate::poll_option(self).map_or_else(default, map)
+    }
+    fn ok_or<E>(self, error: E) -> Result<T, E>
+    where
+        T: Sized
+    {
+        crate::poll_option(self).ok_or(error)
+    }
+    fn ok_or_else<E, F>(self, error: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+        T: Sized
+    {
+        crate::poll_option(self).ok_or_else(error)
+    }
+    fn as_deref<'a>(&'a self) -> Self::AsDeref<'a>
+    where
+        T: Deref + 'a
+    {
+        crate::option_poll(Maybe::<T>::option_ref(self).map(Deref::deref))
+    }
+    fn as_deref_mut<'a>(&'a mut self) -> Self::AsDerefMut<'a>
+    where
+        T: DerefMut + 'a
+    {
+        crate::option_poll(Maybe::<T>::option_mut(self).map(DerefMut::deref_mut))
+    }
+    fn copied(&self) -> Self::Copied
+    where
+        T: Copied<Output: Copy>,
+        (): StaticMaybe<<T as Copied>::Output>
+    {
+        Maybe::<T>::as_ref(self)
+            .map(crate::copy_ref)
+    }
+    fn cloned(&self) -> Self::Copied
+    where
+        T: Copied<Output: Clone>,
+        T: Sized,
+        (): StaticMaybe<<T as Copied>::Output>
+    {
+        Maybe::<T>::as_ref(self)
+            .map(crate::clone_ref)
+    }
+
+    fn option(self) -> Option<T>
+    {
+        crate::poll_option(self)
+    }
+    fn option_ref(&self) -> Option<&T>
+    {
+        match self
+        {
+            Poll::Ready(value) => Some(value),
+            Poll::Pending => None
+        }
+    }
+    fn option_mut(&mut self) -> Option<&mut T>
+    {
+        match self
+        {
+            Poll::Ready(value) => Some(value),
+            Poll::Pending => None
+        }
+    }
+    fn option_pin_ref(self: Pin<&Self>) -> Option<Pin<&T>>
+    {
+        Maybe::<T>::option_ref(self.get_ref())
+            .map(|value| unsafe {
+                Pin::new_unchecked(value)
+            })
+    }
+    fn option_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>>
+    {
+        unsafe {
+            Maybe::<T>::option_mut(self.get_unchecked_mut())
+                .map(|value| Pin::new_unchecked(value))
+        }
+    }
+
+    fn pure(self) -> Self::Pure
+    where
+        T: StaticMaybe<T>,
+        (): StaticMaybe<T>
+    {
+        crate::poll_option(self)
+    }
+    fn pure_ref<'a>(&'a self) -> Self::PureRef<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_ref(self)
+    }
+    fn pure_mut<'a>(&'a mut self) -> Self::PureMut<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_mut(self)
+    }
+    fn pure_pin_ref<'a>(self: Pin<&'a Self>) -> Self::PurePinRef<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_pin_ref(self)
+    }
+    fn pure_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::PurePinMut<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::option_pin_mut(self)
+    }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        crate::private::_PollKind::<U>::into_poll(pure)
+    }
+}
+impl<T> /*const*/ Maybe<T> for [T; 0]
+{
+    const IS_MAYBE_SOME: bool = false;
+    const IS_MAYBE_NONE: bool = true;
+
+    type Pure = <() as Maybe<T>>::Pure
+    where
+        T: StaticMaybe<T>,
+        (): StaticMaybe<T>;
+    type PureRef<'a> = <Self::AsRef<'a> as Maybe<&'a T>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PureMut<'a> = <Self::AsMut<'a> as Maybe<&'a mut T>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PurePinRef<'a> = <Self::AsPinRef<'a> as Maybe<Pin<&'a T>>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PurePinMut<'a> = <Self::AsPinMut<'a> as Maybe<Pin<&'a mut T>>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+
+    type Mapped<U> = [U; 0]
+    where
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Copied = Self::Mapped<<T as Copied>::Output>
+    where
+        T: Copied,
+        (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = [U; 0]
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = <P as crate::private::_ArrayKind<U>>::Output
+    where
+        P: PureMaybe<U>;
+
+    fn is_some(&self) -> bool
+    {
+        false
+    }
+    fn is_none(&self) -> bool
+    {
+        true
+    }
+    fn as_ref<'a>(&'a self) -> Self::AsRef<'a>
+    where
+        T: 'a
+    {
+        []
+    }
+    fn as_mut<'a>(&'a mut self) -> Self::AsMut<'a>
+    where
+        T: 'a
+    {
+        []
+    }
+    fn as_pin_ref<'a>(self: Pin<&'a Self>) -> Self::AsPinRef<'a>
+    where
+        T: 'a
+    {
+        []
+    }
+    fn as_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::AsPinMut<'a>
+    where
+        T: 'a
+    {
+        []
+    }
+    fn as_slice(&self) -> &[T]
+    where
+        T: Sized
+    {
+        &[]
+    }
+    fn as_mut_slice(&mut self) -> &mut [T]
+    where
+        T: Sized
+    {
+        &mut []
+    }
+    fn expect(self, msg: &str) -> T
+    where
+        T: Sized
+    {
+        crate::on_unwrap_empty_msg(msg)
+    }
+    fn unwrap(self) -> T
+    where
+        T: Sized
+    {
+        crate::on_unwrap_empty()
+    }
+    fn unwrap_ref(&self) -> &T
+    {
+        crate::on_unwrap_empty()
+    }
+    fn unwrap_mut(&mut self) -> &mut T
+    {
+        crate::on_unwrap_empty()
+    }
+    fn unwrap_pin_ref<'a>(self: Pin<&'a Self>) -> Pin<&'a T>
+    where
+        T: 'a
+    {
+        crate::on_unwrap_empty()
+    }
+    fn unwrap_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut T>
+    where
+        T: 'a
+    {
+        crate::on_unwrap_empty()
+    }
+    fn unwrap_or(self, default: T) -> T
+    where
+        T: Sized
+    {
+        default
+    }
+    fn unwrap_ref_or<'a>(&'a self, default: &'a T) -> &'a T
+    where
+        T: 'a
+    {
+        default
+    }
+    fn unwrap_mut_or<'a>(&'a mut self, default: &'a mut T) -> &'a mut T
+    where
+        T: 'a
+    {
+        default
+    }
+    fn unwrap_pin_ref_or<'a>(self: Pin<&'a Self>, default: Pin<&'a T>) -> Pin<&'a T>
+    where
+        T: 'a
+    {
+        default
+    }
+    fn unwrap_pin_mut_or<'a>(self: Pin<&'a mut Self>, default: Pin<&'a mut T>) -> Pin<&'a mut T>
+    where
+        T: 'a
+    {
+        default
+    }
+    fn unwrap_or_else<F>(self, default: F) -> T
+    where
+        F: FnOnce() -> T,
+        T: Sized
+    {
+        default()
+    }
+    fn unwrap_ref_or_else<'a, F>(&'a self, default: F) -> &'a T
+    where
+        F: FnOnce() -> &'a T,
+        T: 'a
+    {
+        default()
+    }
+    fn unwrap_mut_or_else<'a, F>(&'a mut self, default: F) -> &'a mut T
+    where
+        F: FnOnce() -> &'a mut T,
+        T: 'a
+    {
+        default()
+    }
+    fn unwrap_pin_ref_or_else<'a, F>(self: Pin<&'a Self>, default: F) -> Pin<&'a T>
+    where
+        F: FnOnce() -> Pin<&'a T>,
+        T: 'a
+    {
+        default()
+    }
+    fn unwrap_pin_mut_or_else<'a, F>(self: Pin<&'a mut Self>, default: F) -> Pin<&'a mut T>
+    where
+        F: FnOnce() -> Pin<&'a mut T>,
+        T: 'a
+    {
+        default()
+    }
+    fn unwrap_or_default(self) -> T
+    where
+        T: Sized + Default
+    {
+        T::default()
+    }
+    fn map<U, F>(self, _: F) -> Self::Mapped<U>
+    where
+        F: FnOnce(T) -> U,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        []
+    }
+    fn map_maybe<U, M, F>(self, _: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        []
+    }
+    fn map_or<U, F>(self, default: U, _: F) -> U
+    where
+        F: FnOnce(T) -> U,
+        T: Sized
+    {
+        default
+    }
+    fn map_or_else<U, D, F>(self, default: D, _: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(T) -> U,
+        T: Sized
+    {
+        default()
+    }
+    fn ok_or<E>(self, error: E) -> Result<T, E>
+    where
+        T: Sized
+    {
+        Err(error)
+    }
+    fn ok_or_else<E, F>(self, error: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+        T: Sized
+    {
+        Err(error())
+    }
+    fn as_deref<'a>(&'a self) -> Self::AsDeref<'a>
+    where
+        T: Deref + 'a
+    {
+        []
+    }
+    fn as_deref_mut<'a>(&'a mut self) -> Self::AsDerefMut<'a>
+    where
+        T: DerefMut + 'a
+    {
+        []
+    }
+    fn copied(&self) -> Self::Copied
+    where
+        T: Copied<Output: Copy>,
+        (): StaticMaybe<<T as Copied>::Output>
+    {
+        []
+    }
+    fn cloned(&self) -> Self::Copied
+    where
+        T: Copied<Output: Clone>,
+        (): StaticMaybe<<T as Copied>::Output>
+    {
+        []
+    }
+
+    fn option(self) -> Option<T>
+    where
+        T: Sized
+    {
+        None
+    }
+    fn option_ref(&self) -> Option<&T>
+    {
+        None
+    }
+    fn option_mut(&mut self) -> Option<&mut T>
+    {
+        None
+    }
+    fn option_pin_ref(self: Pin<&Self>) -> Option<Pin<&T>>
+    {
+        None
+    }
+    fn option_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>>
+    {
+        None
+    }
+
+    fn pure(self) -> Self::Pure
+    where
+        T: StaticMaybe<T>,
+        (): StaticMaybe<T>,
+        Self::Pure: Sized
     {
         crate::assume_same(())
     }
@@ -2556,6 +3620,12 @@ impl<T> /*const*/ Maybe<T> for [T; 0]
     {
 
     }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        crate::private::_ArrayKind::<U>::into_array(pure)
+    }
 }
 impl<T> /*const*/ Maybe<T> for [T; 1]
 {
@@ -2591,6 +3661,14 @@ impl<T> /*const*/ Maybe<T> for [T; 1]
     where
         T: Copied,
         (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = M
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = <P as crate::private::_ArrayKind<U>>::Output
+    where
+        P: PureMaybe<U>;
 
     fn is_some(&self) -> bool
     {
@@ -2761,6 +3839,17 @@ impl<T> /*const*/ Maybe<T> for [T; 1]
     {
         [map(self.unwrap())]
     }
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        let [value] = self;
+        map(value)
+    }
     fn map_or<U, F>(self, _: U, map: F) -> U
     where
         F: FnOnce(T) -> U,
@@ -2882,6 +3971,12 @@ impl<T> /*const*/ Maybe<T> for [T; 1]
             self.map_unchecked_mut(|this| &mut this[0])
         }
     }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        crate::private::_ArrayKind::<U>::into_array(pure)
+    }
 }
 
 #[cfg(test)]
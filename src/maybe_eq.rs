@@ -0,0 +1,65 @@
+use crate::Maybe;
+
+/// Equality comparisons between any two [`Maybe`](crate::Maybe)-types containing the same kind of value.
+/// 
+/// Two maybes are equal if they're both empty, or if they both contain values that are equal,
+/// regardless of how the maybes are represented. This means `OptCell<T, true>`, `Option<T>`, `[T; 1]` and `T` can all be
+/// compared with each other, the same way [`Option`](core::option::Option)s are compared.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be compared as a maybe of `{T}`",
+    label = "expected a maybe of a `{T}` that implements `PartialEq`",
+    note = "`MaybeEq<{T}>` is implemented for every `Maybe<{T}>`"
+)]
+pub trait MaybeEq<T>: Maybe<T>
+where
+    T: ?Sized
+{
+    /// Returns `true` if both maybes are empty, or if both contain values that are equal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(MaybeEq::<i32>::maybe_eq(&OptCell::some(777), &Some(777)));
+    /// assert!(MaybeEq::<i32>::maybe_eq(&[777], &777));
+    /// assert!(MaybeEq::<i32>::maybe_eq(&(), &None));
+    /// assert!(MaybeEq::<i32>::maybe_eq(&OptCell::<i32, false>::none(), &([] as [i32; 0])));
+    /// 
+    /// assert!(!MaybeEq::<i32>::maybe_eq(&777, &()));
+    /// assert!(!MaybeEq::<i32>::maybe_eq(&[777], &Some(666)));
+    /// ```
+    fn maybe_eq<Rhs>(&self, other: &Rhs) -> bool
+    where
+        Rhs: Maybe<T> + ?Sized,
+        T: PartialEq
+    {
+        self.option_ref() == other.option_ref()
+    }
+    /// Returns `true` if one of the maybes is empty while the other isn't, or if they both contain values that are not equal.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(MaybeEq::<i32>::maybe_ne(&777, &()));
+    /// assert!(MaybeEq::<i32>::maybe_ne(&[777], &Some(666)));
+    /// 
+    /// assert!(!MaybeEq::<i32>::maybe_ne(&OptCell::some(777), &Some(777)));
+    /// ```
+    fn maybe_ne<Rhs>(&self, other: &Rhs) -> bool
+    where
+        Rhs: Maybe<T> + ?Sized,
+        T: PartialEq
+    {
+        !self.maybe_eq(other)
+    }
+}
+impl<M, T> MaybeEq<T> for M
+where
+    M: Maybe<T> + ?Sized,
+    T: ?Sized
+{
+
+}
\ No newline at end of file
@@ -0,0 +1,217 @@
+use core::{marker::PhantomData, task::Poll};
+
+use crate::{Maybe, NotVoid};
+
+/// A family of maybes, that can be named before there is any value of the maybe.
+/// 
+/// Where [`Maybe::Mapped`](Maybe::Mapped) gives the same kind of maybe over another type from a maybe you already have,
+/// a family lets generic containers decide on a kind of maybe for all their fields at once.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// struct Table<F>
+/// where
+///     F: MaybeFamily
+/// {
+///     a: F::Of<u8>,
+///     b: F::Of<&'static str>
+/// }
+/// 
+/// let full = Table::<SomeFamily> {
+///     a: 1,
+///     b: "b"
+/// };
+/// let empty = Table::<NoneFamily> {
+///     a: (),
+///     b: ()
+/// };
+/// let runtime = Table::<OptionFamily> {
+///     a: Some(1),
+///     b: None
+/// };
+/// 
+/// assert_eq!(full.b, "b");
+/// assert_eq!(core::mem::size_of_val(&empty), 0);
+/// assert!(Maybe::<u8>::is_some(&runtime.a) && Maybe::<&str>::is_none(&runtime.b));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a family of maybes",
+    label = "expected a maybe family marker",
+    note = "the maybe families are `OptionFamily`, `SomeFamily`, `NoneFamily`, `ResultFamily<E>`, `PollFamily` and `ArrayFamily<IS_SOME>`"
+)]
+#[cfg_attr(feature = "opt_cell", diagnostic::on_unimplemented(note = "`OptCellFamily<IS_SOME>` is also a maybe family"))]
+#[cfg_attr(feature = "patch", diagnostic::on_unimplemented(note = "`PatchFamily` is also a maybe family"))]
+pub trait MaybeFamily
+{
+    /// The maybe of this family that may contain a `T`.
+    type Of<T>: Maybe<T>
+    where
+        T: NotVoid;
+}
+
+/// The family of [`Option`](core::option::Option)s.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<OptionFamily as MaybeFamily>::Of<i32>, Option<i32>);
+/// ```
+pub struct OptionFamily;
+
+impl MaybeFamily for OptionFamily
+{
+    type Of<T> = Option<T>
+    where
+        T: NotVoid;
+}
+
+/// The family of maybes that are just the value itself, and therefore always contain a value.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<SomeFamily as MaybeFamily>::Of<i32>, i32);
+/// ```
+pub struct SomeFamily;
+
+impl MaybeFamily for SomeFamily
+{
+    type Of<T> = T
+    where
+        T: NotVoid;
+}
+
+/// The family of maybes that are `()`, and therefore never contain a value.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<NoneFamily as MaybeFamily>::Of<i32>, ());
+/// ```
+pub struct NoneFamily;
+
+impl MaybeFamily for NoneFamily
+{
+    type Of<T> = ()
+    where
+        T: NotVoid;
+}
+
+/// The family of [`Result`](core::result::Result)s with the error type `E`.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<ResultFamily<&str> as MaybeFamily>::Of<i32>, Result<i32, &str>);
+/// ```
+pub struct ResultFamily<E>(PhantomData<E>);
+
+impl<E> MaybeFamily for ResultFamily<E>
+{
+    type Of<T> = Result<T, E>
+    where
+        T: NotVoid;
+}
+
+/// The family of [`Poll`](core::task::Poll)s, where [`Pending`](core::task::Poll::Pending) is the empty maybe.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<PollFamily as MaybeFamily>::Of<i32>, core::task::Poll<i32>);
+/// ```
+pub struct PollFamily;
+
+impl MaybeFamily for PollFamily
+{
+    type Of<T> = Poll<T>
+    where
+        T: NotVoid;
+}
+
+/// The family of arrays that are either of length one, if `IS_SOME` is true, or empty.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<ArrayFamily<true> as MaybeFamily>::Of<i32>, [i32; 1]);
+/// assert_type_eq_all!(<ArrayFamily<false> as MaybeFamily>::Of<i32>, [i32; 0]);
+/// ```
+pub struct ArrayFamily<const IS_SOME: bool>;
+
+impl MaybeFamily for ArrayFamily<true>
+{
+    type Of<T> = [T; 1]
+    where
+        T: NotVoid;
+}
+impl MaybeFamily for ArrayFamily<false>
+{
+    type Of<T> = [T; 0]
+    where
+        T: NotVoid;
+}
+
+/// The family of [`OptCell`](crate::OptCell)s, that contain a value if `IS_SOME` is true.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<OptCellFamily<true> as MaybeFamily>::Of<i32>, OptCell<i32, true>);
+/// assert_type_eq_all!(<OptCellFamily<false> as MaybeFamily>::Of<i32>, OptCell<i32, false>);
+/// ```
+#[cfg(feature = "opt_cell")]
+pub struct OptCellFamily<const IS_SOME: bool>;
+
+#[cfg(feature = "opt_cell")]
+impl<const IS_SOME: bool> MaybeFamily for OptCellFamily<IS_SOME>
+{
+    type Of<T> = crate::OptCell<T, IS_SOME>
+    where
+        T: NotVoid;
+}
+
+/// The family of [`Patch`](crate::Patch)es.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(<PatchFamily as MaybeFamily>::Of<i32>, Patch<i32>);
+/// ```
+#[cfg(feature = "patch")]
+pub struct PatchFamily;
+
+#[cfg(feature = "patch")]
+impl MaybeFamily for PatchFamily
+{
+    type Of<T> = crate::Patch<T>
+    where
+        T: NotVoid;
+}
\ No newline at end of file
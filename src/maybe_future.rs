@@ -0,0 +1,241 @@
+use core::{future::Future, pin::Pin, task::{Context, Poll}};
+
+use crate::{Maybe, NotVoid, StaticMaybe};
+
+/// An extension trait for [`Maybe`](crate::Maybe)-types containing a [`Future`](core::future::Future).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a maybe of the future `{F}`",
+    label = "expected a maybe containing a future",
+    note = "`MaybeFuture<{F}>` is implemented for every `Maybe<{F}>` where `{F}` is a future"
+)]
+pub trait MaybeFuture<F>: Maybe<F>
+where
+    F: Future
+{
+    /// Returns a future that awaits the internal future, if it exists, and resolves to the same kind of maybe containing its output.
+    /// 
+    /// If the maybe is empty, the returned future resolves immediately.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// use core::{future::{ready, Future, Ready}, pin::pin, task::{Context, Poll, Waker}};
+    /// 
+    /// fn block_on<F>(future: F) -> F::Output
+    /// where
+    ///     F: Future
+    /// {
+    ///     let mut future = pin!(future);
+    ///     let mut cx = Context::from_waker(Waker::noop());
+    ///     loop
+    ///     {
+    ///         if let Poll::Ready(output) = future.as_mut().poll(&mut cx)
+    ///         {
+    ///             return output
+    ///         }
+    ///     }
+    /// }
+    /// 
+    /// let maybe = Some(ready(777));
+    /// let empty: Option<Ready<i32>> = None;
+    /// 
+    /// assert_eq!(block_on(MaybeFuture::<Ready<i32>>::await_maybe(maybe)), Some(777));
+    /// assert_eq!(block_on(MaybeFuture::<Ready<i32>>::await_maybe(empty)), None);
+    /// 
+    /// assert_eq!(block_on(MaybeFuture::<Ready<i32>>::await_maybe(ready(777))), 777);
+    /// assert_eq!(block_on(MaybeFuture::<Ready<i32>>::await_maybe(())), ());
+    /// assert_eq!(block_on(MaybeFuture::<Ready<i32>>::await_maybe([ready(777)])), [777]);
+    /// assert_eq!(block_on(MaybeFuture::<Ready<i32>>::await_maybe(OptCell::some(ready(777)))), OptCell::some(777));
+    /// 
+    /// assert_eq!(block_on(MaybeFuture::<Ready<()>>::await_maybe(Some(ready(())))), Some(()));
+    /// ```
+    fn await_maybe(self) -> AwaitMaybe<Self, F>
+    where
+        Self: Sized
+    {
+        AwaitMaybe::new(self)
+    }
+}
+impl<M, F> MaybeFuture<F> for M
+where
+    M: Maybe<F>,
+    F: Future
+{
+
+}
+
+/// A future that awaits the future inside a [`Maybe`](crate::Maybe), if it exists.
+/// 
+/// This is returned by [`MaybeFuture::await_maybe`].
+/// 
+/// It only implements [`Future`] when the output of the inner future can be the value of a maybe, since it resolves
+/// to the same kind of maybe containing that output. This holds for `()` itself, but not for outputs that contain
+/// `()`, or for the output of a generic future that isn't bounded by [`NotVoid`](crate::NotVoid).
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// use core::future::{ready, Future, Ready};
+/// 
+/// fn assert_future<F>(_: F)
+/// where
+///     F: Future
+/// {
+/// 
+/// }
+/// 
+/// assert_future(MaybeFuture::<Ready<((), i32)>>::await_maybe(Some(ready(((), 777)))));
+/// ```
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct AwaitMaybe<M, F>
+where
+    M: Maybe<F>,
+    F: Future
+{
+    inner: Option<<M as Maybe<F>>::Mapped<MaybeDone<F>>>
+}
+
+impl<M, F> AwaitMaybe<M, F>
+where
+    M: Maybe<F>,
+    F: Future
+{
+    fn new(maybe: M) -> Self
+    {
+        Self {
+            inner: Some(maybe.map(MaybeDone::Future))
+        }
+    }
+}
+
+impl<M, F> Future for AwaitMaybe<M, F>
+where
+    M: Maybe<F>,
+    F: Future,
+    (): StaticMaybe<F::Output>,
+    <M as Maybe<F>>::Mapped<MaybeDone<F>>: Maybe<MaybeDone<F>, Mapped<F::Output> = <M as Maybe<F>>::Mapped<F::Output>>
+{
+    type Output = <M as Maybe<F>>::Mapped<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output>
+    {
+        let this = unsafe {
+            self.get_unchecked_mut()
+        };
+        let inner = this.inner.as_mut()
+            .expect("`AwaitMaybe` polled after completion");
+        let pinned = unsafe {
+            Pin::new_unchecked(inner)
+        };
+        if let Some(done) = Maybe::<Pin<&mut MaybeDone<F>>>::option(pinned.pure_pin_mut())
+        {
+            core::task::ready!(done.poll_done(cx));
+        }
+        let inner = this.inner.take()
+            .unwrap();
+        Poll::Ready(inner.map(|done| unsafe {
+            done.into_output()
+        }))
+    }
+}
+
+enum MaybeDone<F>
+where
+    F: Future
+{
+    Future(F),
+    Done(F::Output)
+}
+
+impl<F> NotVoid for MaybeDone<F>
+where
+    F: Future
+{
+
+}
+
+impl<F> MaybeDone<F>
+where
+    F: Future
+{
+    fn poll_done(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()>
+    {
+        if let MaybeDone::Future(future) = unsafe {
+            self.as_mut().get_unchecked_mut()
+        }
+        {
+            let output = core::task::ready!(unsafe {
+                Pin::new_unchecked(future)
+            }.poll(cx));
+            self.set(MaybeDone::Done(output));
+        }
+        Poll::Ready(())
+    }
+
+    /// # Safety
+    /// 
+    /// [`MaybeDone::poll_done`] must have returned [`Poll::Ready`], which leaves it in the done state.
+    unsafe fn into_output(self) -> F::Output
+    {
+        match self
+        {
+            MaybeDone::Done(output) => output,
+            MaybeDone::Future(_) => unsafe {
+                core::hint::unreachable_unchecked()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use core::{future::Future, pin::{pin, Pin}, task::{Context, Poll, Waker}};
+
+    use crate::*;
+
+    struct YieldOnce(bool, i32);
+
+    impl Future for YieldOnce
+    {
+        type Output = i32;
+
+        fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<i32>
+        {
+            if !self.0
+            {
+                self.0 = true;
+                return Poll::Pending
+            }
+            Poll::Ready(self.1)
+        }
+    }
+
+    fn poll_count<F>(future: F) -> (usize, F::Output)
+    where
+        F: Future
+    {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut pending = 0;
+        loop
+        {
+            match future.as_mut().poll(&mut cx)
+            {
+                Poll::Ready(output) => return (pending, output),
+                Poll::Pending => pending += 1
+            }
+        }
+    }
+
+    #[test]
+    fn pending()
+    {
+        assert_eq!(poll_count(MaybeFuture::<YieldOnce>::await_maybe(YieldOnce(false, 777))), (1, 777));
+        assert_eq!(poll_count(MaybeFuture::<YieldOnce>::await_maybe(Some(YieldOnce(false, 777)))), (1, Some(777)));
+        assert_eq!(poll_count(MaybeFuture::<YieldOnce>::await_maybe([YieldOnce(false, 777)])), (1, [777]));
+        assert_eq!(poll_count(MaybeFuture::<YieldOnce>::await_maybe(OptCell::some(YieldOnce(false, 777)))), (1, OptCell::some(777)));
+        assert_eq!(poll_count(MaybeFuture::<YieldOnce>::await_maybe(())), (0, ()));
+        assert_eq!(poll_count(MaybeFuture::<YieldOnce>::await_maybe(None)), (0, None));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use core::hash::{Hash, Hasher};
+
+use crate::Maybe;
+
+/// Hashing of any [`Maybe`](crate::Maybe)-type, consistent with [`MaybeEq`](crate::MaybeEq).
+/// 
+/// Every representation is hashed exactly like the equivalent [`Option<T>`](core::option::Option) would be, so maybes that are
+/// [`maybe_eq`](crate::MaybeEq::maybe_eq) also produce the same hash.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be hashed as a maybe of `{T}`",
+    label = "expected a maybe of a `{T}` that implements `Hash`",
+    note = "`MaybeHash<{T}>` is implemented for every `Maybe<{T}>`"
+)]
+pub trait MaybeHash<T>: Maybe<T>
+where
+    T: ?Sized
+{
+    /// Feeds the maybe into the given [`Hasher`](core::hash::Hasher), as if it were an [`Option<T>`](core::option::Option).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// use std::hash::{DefaultHasher, Hash, Hasher};
+    /// 
+    /// fn hash<M>(maybe: &M) -> u64
+    /// where
+    ///     M: MaybeHash<i32>
+    /// {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     maybe.maybe_hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    /// 
+    /// let mut hasher = DefaultHasher::new();
+    /// Some(777).hash(&mut hasher);
+    /// let some = hasher.finish();
+    /// 
+    /// assert_eq!(hash(&777), some);
+    /// assert_eq!(hash(&[777]), some);
+    /// assert_eq!(hash(&OptCell::some(777)), some);
+    /// assert_eq!(hash(&Some(777)), some);
+    /// 
+    /// let mut hasher = DefaultHasher::new();
+    /// None::<i32>.hash(&mut hasher);
+    /// let none = hasher.finish();
+    /// 
+    /// assert_eq!(hash(&()), none);
+    /// assert_eq!(hash(&([] as [i32; 0])), none);
+    /// assert_eq!(hash(&OptCell::<i32, false>::none()), none);
+    /// assert_eq!(hash(&None), none);
+    /// ```
+    fn maybe_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+        T: Hash
+    {
+        self.option_ref().hash(state)
+    }
+}
+impl<M, T> MaybeHash<T> for M
+where
+    M: Maybe<T> + ?Sized,
+    T: ?Sized
+{
+
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::StaticMaybe;
+
+/// Evaluates a block only if a [`StaticMaybe`](crate::StaticMaybe)-type contains a value, otherwise evaluates the `else`-block.
+/// 
+/// As opposed to `if O::IS_MAYBE_SOME { .. } else { .. }`, the two branches may have different types. The result has
+/// the type [`StaticMaybe::MaybeOr<A, B>`](crate::StaticMaybe::MaybeOr), where `A` is the type of the first block and
+/// `B` is the type of the `else`-block. If the `else`-block is omitted, it defaults to `()`.
+/// 
+/// This is built on [`StaticMaybe::maybe_or_from_fn`](crate::StaticMaybe::maybe_or_from_fn), so the block that isn't
+/// selected is never run.
+/// 
+/// # Examples
+/// 
+/// Deciding by type:
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn bits<O>() -> <O as StaticMaybe<u8>>::MaybeOr<u32, &'static str>
+/// where
+///     O: StaticMaybe<u8>,
+///     <O as StaticMaybe<u8>>::MaybeOr<u32, &'static str>: Sized
+/// {
+///     maybe_if!(if O: StaticMaybe<u8> { u8::BITS } else { "nothing" })
+/// }
+/// 
+/// assert_eq!(bits::<u8>(), 8);
+/// assert_eq!(bits::<()>(), "nothing");
+/// assert_eq!(bits::<[u8; 1]>(), 8);
+/// assert_eq!(bits::<OptCell<u8, false>>(), "nothing");
+/// ```
+/// 
+/// Binding the value:
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn describe<O>(maybe: O) -> <O as StaticMaybe<i32>>::MaybeOr<String, &'static str>
+/// where
+///     O: StaticMaybe<i32>,
+///     <O as StaticMaybe<i32>>::MaybeOr<String, &'static str>: Sized
+/// {
+///     maybe_if!(if let value = maybe: StaticMaybe<i32> { format!("{value}") } else { "nothing" })
+/// }
+/// 
+/// assert_eq!(describe(777), "777");
+/// assert_eq!(describe(()), "nothing");
+/// assert_eq!(describe(OptCell::some(777)), "777");
+/// assert_eq!(describe([] as [i32; 0]), "nothing");
+/// ```
+#[macro_export]
+macro_rules! maybe_if {
+    (if let $pat:pat = $maybe:ident : StaticMaybe<$t:ty> $then:block else $or:block) => {
+        $crate::maybe_if_let::<_, $t, _, _, _>($maybe, move |$pat| $then, move || $or)
+    };
+    (if let $pat:pat = $maybe:ident : StaticMaybe<$t:ty> $then:block) => {
+        $crate::maybe_if!(if let $pat = $maybe: StaticMaybe<$t> $then else {})
+    };
+    (if $maybe:ty : StaticMaybe<$t:ty> $then:block else $or:block) => {
+        <$maybe as $crate::StaticMaybe<$t>>::maybe_or_from_fn(move || $then, move || $or)
+    };
+    (if $maybe:ty : StaticMaybe<$t:ty> $then:block) => {
+        $crate::maybe_if!(if $maybe: StaticMaybe<$t> $then else {})
+    };
+}
+
+/// Runs `maybe` with the value of `value` if the type contains a value, otherwise runs `or`.
+/// 
+/// This is what [`maybe_if!`](crate::maybe_if) expands to when binding a value.
+#[doc(hidden)]
+pub fn maybe_if_let<S, T, M, O, R>(value: S, maybe: M, or: O) -> S::MaybeOr<R, O::Output>
+where
+    S: StaticMaybe<T>,
+    M: FnOnce(T) -> R,
+    O: FnOnce<()>,
+    S::MaybeOr<R, O::Output>: Sized
+{
+    S::maybe_or_from_fn(move || maybe(value.unwrap()), or)
+}
\ No newline at end of file
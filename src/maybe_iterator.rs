@@ -0,0 +1,459 @@
+use core::{iter::{FusedIterator, Product, Sum}, marker::PhantomData};
+
+use crate::{Maybe, StaticMaybe};
+
+/// An extension trait for [`Iterator`](core::iter::Iterator)s, with adapters that work on any [`Maybe`](crate::Maybe)-type.
+/// 
+/// Where [`Iterator::flatten`](core::iter::Iterator::flatten) and [`Iterator::filter_map`](core::iter::Iterator::filter_map)
+/// only work with [`Option`](core::option::Option)s, these also work with compile-time managed maybes, and use their
+/// static guarantees to give exact size hints.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an iterator",
+    label = "expected an iterator",
+    note = "`MaybeIteratorExt` is implemented for every `Iterator`"
+)]
+pub trait MaybeIteratorExt: Iterator
+{
+    /// Creates an iterator over the values of the maybes in this iterator, skipping the empty ones.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let options = [Some(1), None, Some(3)];
+    /// let flattened: Vec<i32> = options.into_iter().flatten_maybes::<i32>().collect();
+    /// 
+    /// assert_eq!(flattened, [1, 3]);
+    /// 
+    /// let cells = [OptCell::some(1), OptCell::some(2)].into_iter().flatten_maybes::<i32>();
+    /// 
+    /// assert_eq!(cells.len(), 2);
+    /// 
+    /// let empty = [OptCell::<i32, false>::none(); 3].into_iter().flatten_maybes::<i32>();
+    /// 
+    /// assert_eq!(empty.size_hint(), (0, Some(0)));
+    /// ```
+    fn flatten_maybes<T>(self) -> FlattenMaybes<Self, T>
+    where
+        Self: Sized,
+        Self::Item: Maybe<T>
+    {
+        FlattenMaybes {
+            iter: self,
+            marker: PhantomData
+        }
+    }
+
+    /// Creates an iterator that maps every item into a maybe, and yields the values of the ones that aren't empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let parsed: Vec<i32> = ["1", "two", "3"].into_iter()
+    ///     .filter_map_maybe::<i32, _, _>(|s| s.parse().ok())
+    ///     .collect();
+    /// 
+    /// assert_eq!(parsed, [1, 3]);
+    /// 
+    /// let lengths = ["a", "bc"].into_iter().filter_map_maybe::<usize, _, _>(|s| [s.len()]);
+    /// 
+    /// assert_eq!(lengths.size_hint(), (2, Some(2)));
+    /// assert_eq!(lengths.collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    fn filter_map_maybe<T, M, F>(self, map: F) -> FilterMapMaybe<Self, F, T>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> M,
+        M: Maybe<T>
+    {
+        FilterMapMaybe {
+            iter: self,
+            map,
+            marker: PhantomData
+        }
+    }
+
+    /// Maps the items into maybes, and returns the value of the first one that isn't empty.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut iter = ["one", "2", "3"].into_iter();
+    /// 
+    /// assert_eq!(iter.find_maybe::<i32, _, _>(|s| s.parse().ok()), Some(2));
+    /// assert_eq!(iter.next(), Some("3"));
+    /// 
+    /// assert_eq!([1, 2].into_iter().find_maybe::<i32, _, _>(|_| ()), None);
+    /// ```
+    fn find_maybe<T, M, F>(&mut self, mut map: F) -> Option<T>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> M,
+        M: Maybe<T>,
+        T: Sized
+    {
+        for item in self.by_ref()
+        {
+            if let Some(value) = map(item).option()
+            {
+                return Some(value)
+            }
+        }
+        None
+    }
+
+    /// Sums up the values of the maybes in this iterator, or returns [`None`](core::option::Option::None) if any of
+    /// them are empty.
+    /// 
+    /// Just like summing up an iterator of [`Option`](core::option::Option)s, this stops at the first empty maybe,
+    /// and doesn't consume the rest of the iterator. It works for any kind of maybe, run-time managed or not.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use core::task::Poll;
+    /// use option_trait::*;
+    /// 
+    /// let readings = [Ok::<i32, ()>(1), Ok(2), Ok(3)];
+    /// assert_eq!(readings.into_iter().sum_maybes::<i32, i32>(), Some(6));
+    /// 
+    /// let mut readings = [Poll::Ready(1), Poll::Pending, Poll::Ready(3)].into_iter();
+    /// assert_eq!(readings.by_ref().sum_maybes::<i32, i32>(), None);
+    /// assert_eq!(readings.next(), Some(Poll::Ready(3)));
+    /// 
+    /// let cells = [OptCell::some(1), OptCell::some(2)];
+    /// assert_eq!(cells.into_iter().sum_maybes::<i32, i32>(), Some(3));
+    /// ```
+    fn sum_maybes<T, S>(self) -> Option<S>
+    where
+        Self: Sized,
+        Self::Item: Maybe<T>,
+        S: Sum<T>
+    {
+        let mut empty = false;
+        let sum = self.map_while(|maybe| {
+            let value = maybe.option();
+            empty = value.is_none();
+            value
+        }).sum();
+        if empty
+        {
+            return None
+        }
+        Some(sum)
+    }
+
+    /// Multiplies together the values of the maybes in this iterator, or returns [`None`](core::option::Option::None)
+    /// if any of them are empty.
+    /// 
+    /// Just like multiplying together an iterator of [`Option`](core::option::Option)s, this stops at the first empty
+    /// maybe, and doesn't consume the rest of the iterator. It works for any kind of maybe, run-time managed or not.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let factors = [[2], [3], [4]];
+    /// assert_eq!(factors.into_iter().product_maybes::<i32, i32>(), Some(24));
+    /// 
+    /// let mut factors = [Ok(2), Err("overflow"), Ok(4)].into_iter();
+    /// assert_eq!(factors.by_ref().product_maybes::<i32, i32>(), None);
+    /// assert_eq!(factors.next(), Some(Ok(4)));
+    /// 
+    /// assert_eq!([(); 3].into_iter().product_maybes::<i32, i32>(), None);
+    /// ```
+    fn product_maybes<T, S>(self) -> Option<S>
+    where
+        Self: Sized,
+        Self::Item: Maybe<T>,
+        S: Product<T>
+    {
+        let mut empty = false;
+        let product = self.map_while(|maybe| {
+            let value = maybe.option();
+            empty = value.is_none();
+            value
+        }).product();
+        if empty
+        {
+            return None
+        }
+        Some(product)
+    }
+}
+impl<I> MaybeIteratorExt for I
+where
+    I: Iterator + ?Sized
+{
+
+}
+
+/// Returns the size hint of an iterator of maybes, once the empty ones are skipped.
+const fn maybe_size_hint<M, T>((lower, upper): (usize, Option<usize>)) -> (usize, Option<usize>)
+where
+    M: Maybe<T>
+{
+    if M::IS_NEVER_SOME
+    {
+        (0, Some(0))
+    }
+    else if M::IS_ALWAYS_SOME
+    {
+        (lower, upper)
+    }
+    else
+    {
+        (0, upper)
+    }
+}
+
+/// An iterator over the values of the maybes in another iterator.
+/// 
+/// This is returned by [`MaybeIteratorExt::flatten_maybes`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlattenMaybes<I, T>
+{
+    iter: I,
+    marker: PhantomData<fn() -> T>
+}
+
+impl<I, T> Iterator for FlattenMaybes<I, T>
+where
+    I: Iterator,
+    I::Item: Maybe<T>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T>
+    {
+        self.iter.find_maybe(|maybe| maybe)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        maybe_size_hint::<I::Item, T>(self.iter.size_hint())
+    }
+}
+impl<I, T> DoubleEndedIterator for FlattenMaybes<I, T>
+where
+    I: DoubleEndedIterator,
+    I::Item: Maybe<T>
+{
+    fn next_back(&mut self) -> Option<T>
+    {
+        self.iter.by_ref().rev().find_maybe(|maybe| maybe)
+    }
+}
+impl<I, T> ExactSizeIterator for FlattenMaybes<I, T>
+where
+    I: ExactSizeIterator,
+    I::Item: StaticMaybe<T>
+{
+
+}
+impl<I, T> FusedIterator for FlattenMaybes<I, T>
+where
+    I: FusedIterator,
+    I::Item: Maybe<T>
+{
+
+}
+impl<I, T> Clone for FlattenMaybes<I, T>
+where
+    I: Clone
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            iter: self.iter.clone(),
+            marker: PhantomData
+        }
+    }
+}
+impl<I, T> core::fmt::Debug for FlattenMaybes<I, T>
+where
+    I: core::fmt::Debug
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_struct("FlattenMaybes")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+/// An iterator that maps the items of another iterator into maybes, and yields the values of the ones that aren't empty.
+/// 
+/// This is returned by [`MaybeIteratorExt::filter_map_maybe`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterMapMaybe<I, F, T>
+{
+    iter: I,
+    map: F,
+    marker: PhantomData<fn() -> T>
+}
+
+impl<I, F, M, T> Iterator for FilterMapMaybe<I, F, T>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> M,
+    M: Maybe<T>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T>
+    {
+        self.iter.find_maybe(&mut self.map)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        maybe_size_hint::<M, T>(self.iter.size_hint())
+    }
+}
+impl<I, F, M, T> DoubleEndedIterator for FilterMapMaybe<I, F, T>
+where
+    I: DoubleEndedIterator,
+    F: FnMut(I::Item) -> M,
+    M: Maybe<T>
+{
+    fn next_back(&mut self) -> Option<T>
+    {
+        self.iter.by_ref().rev().find_maybe(&mut self.map)
+    }
+}
+impl<I, F, M, T> ExactSizeIterator for FilterMapMaybe<I, F, T>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item) -> M,
+    M: StaticMaybe<T>
+{
+
+}
+impl<I, F, M, T> FusedIterator for FilterMapMaybe<I, F, T>
+where
+    I: FusedIterator,
+    F: FnMut(I::Item) -> M,
+    M: Maybe<T>
+{
+
+}
+impl<I, F, T> Clone for FilterMapMaybe<I, F, T>
+where
+    I: Clone,
+    F: Clone
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            iter: self.iter.clone(),
+            map: self.map.clone(),
+            marker: PhantomData
+        }
+    }
+}
+impl<I, F, T> core::fmt::Debug for FilterMapMaybe<I, F, T>
+where
+    I: core::fmt::Debug
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_struct("FilterMapMaybe")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use core::{cell::Cell, task::Poll};
+
+    use crate::{MaybeIteratorExt, OptCell};
+
+    struct NotClone;
+
+    #[test]
+    fn sum_short_circuits()
+    {
+        let consumed = Cell::new(0);
+        let readings = [Some(1), None, Some(3)].into_iter().inspect(|_| consumed.set(consumed.get() + 1));
+
+        assert_eq!(readings.sum_maybes::<i32, i32>(), None);
+        assert_eq!(consumed.get(), 2);
+
+        let consumed = Cell::new(0);
+        let readings = [Ok(2), Ok(3), Err(()), Ok(5)].into_iter().inspect(|_| consumed.set(consumed.get() + 1));
+
+        assert_eq!(readings.product_maybes::<i32, i32>(), None);
+        assert_eq!(consumed.get(), 3);
+    }
+
+    #[test]
+    fn sum_mixed()
+    {
+        assert_eq!([Poll::Ready(1), Poll::Ready(2)].into_iter().sum_maybes::<i32, i32>(), Some(3));
+        assert_eq!([Poll::Ready(1), Poll::Pending].into_iter().sum_maybes::<i32, i32>(), None);
+        assert_eq!([[1], [2], [3]].into_iter().product_maybes::<i32, i32>(), Some(6));
+        assert_eq!([OptCell::<i32, false>::none(); 2].into_iter().sum_maybes::<i32, i32>(), None);
+        assert_eq!(core::iter::empty::<Option<i32>>().sum_maybes::<i32, i32>(), Some(0));
+        assert_eq!(core::iter::empty::<()>().product_maybes::<i32, i32>(), Some(1));
+    }
+
+    #[test]
+    fn flatten_exact_size()
+    {
+        let mut cells = [OptCell::some(1), OptCell::some(2), OptCell::some(3)].into_iter().flatten_maybes::<i32>();
+
+        assert_eq!(cells.len(), 3);
+        assert_eq!(cells.next(), Some(1));
+        assert_eq!(cells.len(), 2);
+
+        let empty = [(), ()].into_iter().flatten_maybes::<i32>();
+
+        assert_eq!(empty.len(), 0);
+
+        let mut polls = [Poll::Ready(1), Poll::Pending, Poll::Ready(3)].into_iter().flatten_maybes::<i32>();
+
+        assert_eq!(polls.size_hint(), (0, Some(3)));
+        assert_eq!(polls.next(), Some(1));
+        assert_eq!(polls.next(), Some(3));
+    }
+
+    #[test]
+    fn flatten_double_ended()
+    {
+        let mut options = [Some(1), None, Some(3), None, Some(5)].into_iter().flatten_maybes::<i32>();
+
+        assert_eq!(options.next_back(), Some(5));
+        assert_eq!(options.next(), Some(1));
+        assert_eq!(options.next_back(), Some(3));
+        assert_eq!(options.next_back(), None);
+        assert_eq!(options.next(), None);
+
+        let reversed: Vec<i32> = [OptCell::some(1), OptCell::some(2)].into_iter().flatten_maybes::<i32>().rev().collect();
+
+        assert_eq!(reversed, [2, 1]);
+    }
+
+    #[test]
+    fn flatten_clone_and_debug()
+    {
+        let empty = core::iter::empty::<Option<NotClone>>().flatten_maybes::<NotClone>();
+
+        assert!(empty.clone().next().is_none());
+        assert_eq!(format!("{:?}", empty), "FlattenMaybes { iter: Empty }");
+
+        let cells = [Some(1), None].into_iter().flatten_maybes::<i32>();
+        let mut clone = cells.clone();
+
+        assert_eq!(clone.next(), Some(1));
+        assert_eq!(format!("{:?}", cells), "FlattenMaybes { iter: IntoIter([Some(1), None]) }");
+    }
+}
\ No newline at end of file
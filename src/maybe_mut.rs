@@ -0,0 +1,211 @@
+use core::task::Poll;
+
+use crate::Maybe;
+
+/// A trait for [`Maybe`](crate::Maybe)-types that can be filled with a value in place.
+/// 
+/// This is implemented for every maybe that is able to hold a value, that is `T`, [`Option<T>`](core::option::Option),
+/// [`Result<T, E>`](core::result::Result), [`Poll<T>`](core::task::Poll), `[T; 1]` and `OptCell<T, true>`. Maybes that
+/// never contain a value, like `()`, don't implement it, so using them where a [`MaybeMut`] is required fails to compile.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn cached<O>(cache: &mut O) -> i32
+/// where
+///     O: MaybeMut<i32>
+/// {
+///     *cache.get_or_insert_with(|| 777)
+/// }
+/// 
+/// let mut lazy = None;
+/// assert_eq!(cached(&mut lazy), 777);
+/// assert_eq!(lazy, Some(777));
+/// 
+/// assert_eq!(cached(&mut 666), 666);
+/// assert_eq!(cached(&mut [666]), 666);
+/// assert_eq!(cached(&mut OptCell::some(666)), 666);
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn cached<O>(cache: &mut O) -> i32
+/// where
+///     O: MaybeMut<i32>
+/// {
+///     *cache.get_or_insert_with(|| 777)
+/// }
+/// 
+/// cached(&mut ());
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a maybe of `{T}` that can be filled in place",
+    label = "expected a maybe that is able to hold a `{T}`",
+    note = "the maybes of `{T}` that can be filled in place are `{T}`, `Option<{T}>`, `Result<{T}, E>`, `Poll<{T}>` and `[{T}; 1]`"
+)]
+#[cfg_attr(feature = "opt_cell", diagnostic::on_unimplemented(note = "`OptCell<{T}, true>` can also be filled in place"))]
+#[diagnostic::on_unimplemented(
+    note = "maybes that never contain a value, like `()`, can't be filled"
+)]
+pub trait MaybeMut<T>: Maybe<T>
+{
+    /// Inserts `value` into the maybe, then returns a mutable reference to it.
+    /// 
+    /// If the maybe already contains a value, the old value is dropped.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut option = None;
+    /// let mut array = [32];
+    /// 
+    /// assert_eq!(MaybeMut::<i32>::insert(&mut option, 64), &mut 64);
+    /// assert_eq!(MaybeMut::<i32>::insert(&mut array, 64), &mut 64);
+    /// 
+    /// assert_eq!(option, Some(64));
+    /// assert_eq!(array, [64]);
+    /// ```
+    fn insert(&mut self, value: T) -> &mut T;
+    /// Inserts a value computed from `insert` into the maybe if it's empty, then returns a mutable reference to the contained value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut option = None;
+    /// 
+    /// MaybeMut::<i32>::get_or_insert_with(&mut option, || 32);
+    /// MaybeMut::<i32>::get_or_insert_with(&mut option, || panic!("Won't happen"));
+    /// 
+    /// assert_eq!(option, Some(32));
+    /// 
+    /// let mut value = 32;
+    /// 
+    /// MaybeMut::<i32>::get_or_insert_with(&mut value, || panic!("Won't happen"));
+    /// 
+    /// assert_eq!(value, 32);
+    /// ```
+    fn get_or_insert_with<F>(&mut self, insert: F) -> &mut T
+    where
+        F: FnOnce() -> T;
+    /// Inserts `value` into the maybe if it's empty, then returns a mutable reference to the contained value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut option = None;
+    /// 
+    /// MaybeMut::<i32>::get_or_insert(&mut option, 32);
+    /// MaybeMut::<i32>::get_or_insert(&mut option, 64);
+    /// 
+    /// assert_eq!(option, Some(32));
+    /// ```
+    fn get_or_insert(&mut self, value: T) -> &mut T
+    {
+        self.get_or_insert_with(move || value)
+    }
+    /// Inserts the default value into the maybe if it's empty, then returns a mutable reference to the contained value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut option: Option<i32> = None;
+    /// 
+    /// MaybeMut::<i32>::get_or_insert_default(&mut option);
+    /// 
+    /// assert_eq!(option, Some(0));
+    /// ```
+    fn get_or_insert_default(&mut self) -> &mut T
+    where
+        T: Default
+    {
+        self.get_or_insert_with(T::default)
+    }
+}
+impl<T> MaybeMut<T> for T
+{
+    fn insert(&mut self, value: T) -> &mut T
+    {
+        *self = value;
+        self
+    }
+    fn get_or_insert_with<F>(&mut self, _: F) -> &mut T
+    where
+        F: FnOnce() -> T
+    {
+        self
+    }
+}
+impl<T> MaybeMut<T> for Option<T>
+{
+    fn insert(&mut self, value: T) -> &mut T
+    {
+        self.insert(value)
+    }
+    fn get_or_insert_with<F>(&mut self, insert: F) -> &mut T
+    where
+        F: FnOnce() -> T
+    {
+        self.get_or_insert_with(insert)
+    }
+}
+impl<T, E> MaybeMut<T> for Result<T, E>
+{
+    fn insert(&mut self, value: T) -> &mut T
+    {
+        *self = Ok(value);
+        Maybe::<T>::unwrap_mut(self)
+    }
+    fn get_or_insert_with<F>(&mut self, insert: F) -> &mut T
+    where
+        F: FnOnce() -> T
+    {
+        if self.is_err()
+        {
+            *self = Ok(insert())
+        }
+        Maybe::<T>::unwrap_mut(self)
+    }
+}
+impl<T> MaybeMut<T> for Poll<T>
+{
+    fn insert(&mut self, value: T) -> &mut T
+    {
+        *self = Poll::Ready(value);
+        Maybe::<T>::unwrap_mut(self)
+    }
+    fn get_or_insert_with<F>(&mut self, insert: F) -> &mut T
+    where
+        F: FnOnce() -> T
+    {
+        if self.is_pending()
+        {
+            *self = Poll::Ready(insert())
+        }
+        Maybe::<T>::unwrap_mut(self)
+    }
+}
+impl<T> MaybeMut<T> for [T; 1]
+{
+    fn insert(&mut self, value: T) -> &mut T
+    {
+        self[0] = value;
+        &mut self[0]
+    }
+    fn get_or_insert_with<F>(&mut self, _: F) -> &mut T
+    where
+        F: FnOnce() -> T
+    {
+        &mut self[0]
+    }
+}
\ No newline at end of file
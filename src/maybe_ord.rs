@@ -0,0 +1,64 @@
+use core::cmp::Ordering;
+
+use crate::{Maybe, MaybeEq};
+
+/// Ordering between any two [`Maybe`](crate::Maybe)-types containing the same kind of value.
+/// 
+/// Empty maybes are ordered before maybes containing a value, and two maybes containing values are ordered by their values,
+/// matching the ordering of [`Option`](core::option::Option), regardless of how the maybes are represented.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be ordered as a maybe of `{T}`",
+    label = "expected a maybe of a `{T}` that implements `PartialOrd`",
+    note = "`MaybeOrd<{T}>` is implemented for every `Maybe<{T}>`"
+)]
+pub trait MaybeOrd<T>: MaybeEq<T>
+where
+    T: ?Sized
+{
+    /// Returns an ordering between the two maybes, if one exists.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// use core::cmp::Ordering;
+    /// 
+    /// assert_eq!(MaybeOrd::<f64>::maybe_partial_cmp(&OptCell::some(1.0), &Some(2.0)), Some(Ordering::Less));
+    /// assert_eq!(MaybeOrd::<f64>::maybe_partial_cmp(&(), &[2.0]), Some(Ordering::Less));
+    /// assert_eq!(MaybeOrd::<f64>::maybe_partial_cmp(&f64::NAN, &Some(2.0)), None);
+    /// ```
+    fn maybe_partial_cmp<Rhs>(&self, other: &Rhs) -> Option<Ordering>
+    where
+        Rhs: Maybe<T> + ?Sized,
+        T: PartialOrd
+    {
+        self.option_ref().partial_cmp(&other.option_ref())
+    }
+    /// Returns the ordering between the two maybes. An empty maybe is always less than a maybe containing a value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// use core::cmp::Ordering;
+    /// 
+    /// assert_eq!(MaybeOrd::<i32>::maybe_cmp(&OptCell::some(777), &Some(666)), Ordering::Greater);
+    /// assert_eq!(MaybeOrd::<i32>::maybe_cmp(&[777], &777), Ordering::Equal);
+    /// assert_eq!(MaybeOrd::<i32>::maybe_cmp(&(), &OptCell::some(666)), Ordering::Less);
+    /// assert_eq!(MaybeOrd::<i32>::maybe_cmp(&None, &()), Ordering::Equal);
+    /// ```
+    fn maybe_cmp<Rhs>(&self, other: &Rhs) -> Ordering
+    where
+        Rhs: Maybe<T> + ?Sized,
+        T: Ord
+    {
+        self.option_ref().cmp(&other.option_ref())
+    }
+}
+impl<M, T> MaybeOrd<T> for M
+where
+    M: Maybe<T> + ?Sized,
+    T: ?Sized
+{
+
+}
\ No newline at end of file
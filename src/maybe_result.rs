@@ -0,0 +1,90 @@
+use crate::Maybe;
+
+/// An extension trait for converting any [`Maybe`](crate::Maybe) back into a [`Result`](core::result::Result).
+/// 
+/// A [`Result`](core::result::Result) viewed as a [`Maybe`](crate::Maybe) treats its error as the absence of a value.
+/// This trait lets it round-trip back into a [`Result`](core::result::Result) without losing the original error.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be turned into a `Result<{T}, {E}>`",
+    label = "expected a maybe of `{T}`",
+    note = "`MaybeResult<{T}, {E}>` is implemented for every `Maybe<{T}>`"
+)]
+pub trait MaybeResult<T, E>: Maybe<T>
+{
+    /// Returns an [`Ok`](core::result::Result::Ok) containing the internal value, otherwise returns [`Err`](core::result::Result::Err) containing `error`.
+    /// 
+    /// As opposed to [`Maybe::ok_or`](crate::Maybe::ok_or), a [`Result`](core::result::Result) with the same error type keeps its original error, and `error` is dropped.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let ok: Result<i32, &str> = Ok(777);
+    /// let err: Result<i32, &str> = Err("Original");
+    /// 
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or(ok, "Default"), Ok(777));
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or(err, "Default"), Err("Original"));
+    /// 
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or(Maybe::<i32>::option(err), "Default"), Err("Default"));
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or((), "Default"), Err("Default"));
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or([777], "Default"), Ok(777));
+    /// ```
+    fn result_or(self, error: E) -> Result<T, E>
+    where
+        T: Sized,
+        Self: Sized;
+    /// Returns an [`Ok`](core::result::Result::Ok) containing the internal value, otherwise returns [`Err`](core::result::Result::Err) containing the result of `error`.
+    /// 
+    /// As opposed to [`Maybe::ok_or_else`](crate::Maybe::ok_or_else), a [`Result`](core::result::Result) with the same error type keeps its original error, and `error` is never called.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let err: Result<i32, &str> = Err("Original");
+    /// 
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or_else(err, || unreachable!()), Err("Original"));
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or_else(None, || "Default"), Err("Default"));
+    /// assert_eq!(MaybeResult::<i32, &str>::result_or_else(OptCell::some(777), || "Default"), Ok(777));
+    /// ```
+    fn result_or_else<F>(self, error: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+        T: Sized,
+        Self: Sized;
+}
+impl<M, T, E> MaybeResult<T, E> for M
+where
+    M: Maybe<T> + ?Sized
+{
+    default fn result_or(self, error: E) -> Result<T, E>
+    where
+        T: Sized,
+        Self: Sized
+    {
+        self.ok_or(error)
+    }
+    default fn result_or_else<F>(self, error: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+        T: Sized,
+        Self: Sized
+    {
+        self.ok_or_else(error)
+    }
+}
+impl<T, E> MaybeResult<T, E> for Result<T, E>
+{
+    fn result_or(self, _: E) -> Result<T, E>
+    {
+        self
+    }
+    fn result_or_else<F>(self, _: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E
+    {
+        self
+    }
+}
\ No newline at end of file
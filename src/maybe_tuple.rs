@@ -0,0 +1,81 @@
+use crate::StaticMaybe;
+
+/// A trait for tuples of [`StaticMaybe`](crate::StaticMaybe)-types, where `T` is the tuple of their inner types.
+/// 
+/// This makes it possible to compute, at compile-time, how many of the optional fields of a generic struct are present.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use option_trait::*;
+/// 
+/// fn buffer<A, B>() -> [u8; <(A, B) as MaybeTuple<(u8, u16)>>::SIZE_BYTES]
+/// where
+///     A: StaticMaybe<u8>,
+///     B: StaticMaybe<u16>,
+///     [(); <(A, B) as MaybeTuple<(u8, u16)>>::SIZE_BYTES]:
+/// {
+///     [0; <(A, B) as MaybeTuple<(u8, u16)>>::SIZE_BYTES]
+/// }
+/// 
+/// assert_eq!(buffer::<u8, u16>().len(), 3);
+/// assert_eq!(buffer::<(), u16>().len(), 2);
+/// assert_eq!(buffer::<u8, ()>().len(), 1);
+/// assert_eq!(buffer::<(), ()>().len(), 0);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a tuple of compile-time managed maybes of `{T}`",
+    label = "expected a tuple of compile-time managed maybes, one for each field of `{T}`",
+    note = "each field must be a compile-time managed maybe of the matching field of `{T}`, such as the field type itself, `()`, `[_; 1]`, `[_; 0]` or `OptCell<_, IS_SOME>`",
+    note = "tuples of up to 12 fields are supported"
+)]
+pub trait MaybeTuple<T>
+{
+    /// The number of maybes in the tuple that contain a value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(<(u8, (), [u32; 1]) as MaybeTuple<(u8, u16, u32)>>::SUM, 2);
+    /// assert_eq!(<((), ()) as MaybeTuple<(u8, u16)>>::SUM, 0);
+    /// ```
+    const SUM: usize;
+    /// The total size, in bytes, of the values contained in the tuple.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(<(u8, (), [u32; 1]) as MaybeTuple<(u8, u16, u32)>>::SIZE_BYTES, 5);
+    /// assert_eq!(<((), ()) as MaybeTuple<(u8, u16)>>::SIZE_BYTES, 0);
+    /// ```
+    const SIZE_BYTES: usize;
+}
+
+macro_rules! impl_maybe_tuple {
+    () => {
+        impl MaybeTuple<()> for ()
+        {
+            const SUM: usize = 0;
+            const SIZE_BYTES: usize = 0;
+        }
+    };
+    ($m0:ident: $t0:ident $(, $m:ident: $t:ident)*) => {
+        impl<$m0, $t0 $(, $m, $t)*> MaybeTuple<($t0, $($t,)*)> for ($m0, $($m,)*)
+        where
+            $m0: StaticMaybe<$t0> $(, $m: StaticMaybe<$t>)*
+        {
+            const SUM: usize = <$m0 as StaticMaybe<$t0>>::COUNT $(+ <$m as StaticMaybe<$t>>::COUNT)*;
+            const SIZE_BYTES: usize = <$m0 as StaticMaybe<$t0>>::COUNT*core::mem::size_of::<$t0>()
+                $(+ <$m as StaticMaybe<$t>>::COUNT*core::mem::size_of::<$t>())*;
+        }
+        impl_maybe_tuple!($($m: $t),*);
+    };
+}
+
+impl_maybe_tuple!(M0: T0, M1: T1, M2: T2, M3: T3, M4: T4, M5: T5, M6: T6, M7: T7, M8: T8, M9: T9, M10: T10, M11: T11);
\ No newline at end of file
@@ -1,4 +1,4 @@
-use core::{cell::UnsafeCell, marker::PhantomData, mem::ManuallyDrop, ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive, Yeet}, pin::Pin};
+use core::{cell::UnsafeCell, marker::PhantomData, mem::ManuallyDrop, ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive, Yeet}, pin::Pin, task::Poll};
 
 /// A stupid trait made to avoid conflicting implementations.
 /// 
@@ -8,6 +8,50 @@ use core::{cell::UnsafeCell, marker::PhantomData, mem::ManuallyDrop, ops::{Bound
 /// 
 /// Currently this is the best solution i could find to avoid a conflicting implementation, but it may be subject to change later.
 /// Ideally i'd want to get rid of this thing.
+/// 
+/// # Examples
+/// 
+/// A struct containing `()` can't be the value of a maybe.
+/// 
+/// ```rust,compile_fail,E0277
+/// use option_trait::*;
+/// 
+/// struct Unit(u8, ());
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<Unit>
+/// {
+/// 
+/// }
+/// 
+/// f(());
+/// ```
+/// 
+/// Until it implements [`NotVoid`] manually.
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// struct Unit(u8, ());
+/// 
+/// impl NotVoid for Unit {}
+/// 
+/// fn f<O>(_: O)
+/// where
+///     O: Maybe<Unit>
+/// {
+/// 
+/// }
+/// 
+/// f(());
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is or contains `()`, so it can't be the value of a maybe",
+    label = "`()` here would be ambiguous with the empty maybe",
+    note = "`()` is the empty maybe, so `Maybe<()>` would mean both a value and the absence of one",
+    note = "if `{Self}` is your own type that contains `()`, implement `NotVoid` for it manually"
+)]
 pub auto trait NotVoid {}
 impl !NotVoid for () {}
 
@@ -27,6 +71,7 @@ impl<T> NotVoid for Pin<T> {}
 impl<T> NotVoid for Bound<T> {}
 impl<T> NotVoid for Option<T> {}
 impl<T, E> NotVoid for Result<T, E> {}
+impl<T> NotVoid for Poll<T> {}
 
 impl<T> NotVoid for &T where T: ?Sized {}
 impl<T> NotVoid for &mut T where T: ?Sized {}
@@ -1,5 +1,10 @@
 use crate::{ops::MaybeAndThen, NotVoid, PureMaybe};
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be combined with `{Rhs}` using `and`",
+    label = "expected pure maybes of `{T}` on both sides",
+    note = "both sides must be pure maybes of `{T}`, that is `{T}`, `()` or `Option<{T}>`; other maybes can be turned into their pure form with `Maybe::pure`"
+)]
 pub trait MaybeAnd<T, Rhs>: MaybeAndThen<T, T, Rhs>
 where
     T: ?Sized,
@@ -14,22 +19,6 @@ where
         Rhs: Sized;
 }
 
-impl<Lhs, Rhs, T> MaybeAnd<T, Rhs> for Lhs
-where
-    T: ?Sized,
-    Lhs: PureMaybe<T> + ?Sized,
-    Rhs: PureMaybe<T> + ?Sized
-{
-    default fn and(self, _: Rhs) -> <Self as MaybeAnd<T, Rhs>>::Output
-    where
-        <Self as MaybeAnd<T, Rhs>>::Output: Sized,
-        Self: Sized,
-        Rhs: Sized
-    {
-        unreachable!()
-    }
-}
-
 impl<T> MaybeAnd<T, Option<T>> for Option<T>
 {
     fn and(self, rhs: Option<T>) -> <Self as MaybeAnd<T, Option<T>>>::Output
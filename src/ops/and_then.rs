@@ -1,5 +1,10 @@
 use crate::{NotVoid, PureMaybe};
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be combined with `{Rhs}` using `and_then`",
+    label = "expected a pure maybe of `{T}` and a pure maybe of `{U}`",
+    note = "`{Self}` must be a pure maybe of `{T}` and `{Rhs}` a pure maybe of `{U}`, that is the value itself, `()` or an `Option`; other maybes can be turned into their pure form with `Maybe::pure`"
+)]
 pub trait MaybeAndThen<T, U, Rhs>: PureMaybe<T>
 where
     T: ?Sized,
@@ -17,27 +22,6 @@ where
         Rhs: Sized;
 }
 
-impl<Lhs, Rhs, T, U> MaybeAndThen<T, U, Rhs> for Lhs
-where
-    T: ?Sized,
-    U: ?Sized,
-    Lhs: PureMaybe<T> + ?Sized,
-    Rhs: PureMaybe<U> + ?Sized
-{
-    default type Output = U;
-
-    default fn and_then<F>(self, _: F) -> Self::Output
-    where
-        T: Sized,
-        F: FnOnce(T) -> Rhs,
-        Self::Output: Sized,
-        Self: Sized,
-        Rhs: Sized
-    {
-        unreachable!()
-    }
-}
-
 impl<T, U> MaybeAndThen<T, U, Option<U>> for Option<T>
 {
     type Output = Option<U>;
@@ -0,0 +1,109 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::{ops::MaybeAndThen, PureMaybe};
+
+/// Applies `op` to the values of both maybes, if both have a value, and returns it as the same kind of maybe as
+/// [`MaybeAnd`](crate::ops::MaybeAnd) would.
+fn zip_with<Lhs, Rhs, T, F>(lhs: Lhs, rhs: Rhs, op: F) -> <Lhs as MaybeAndThen<T, T, Rhs>>::Output
+where
+    Lhs: PureMaybe<T> + MaybeAndThen<T, T, Rhs>,
+    Rhs: PureMaybe<T>,
+    F: FnOnce(T, T) -> T,
+    <Lhs as MaybeAndThen<T, T, Rhs>>::Output: Sized
+{
+    MaybeAndThen::<T, T, Rhs>::and_then(lhs, |lhs| crate::private::PureMaybe::<T>::map_value(rhs, |rhs| op(lhs, rhs)))
+}
+
+macro_rules! impl_maybe_arithmetic {
+    ($($(#[$meta:meta])* $trait:ident: $op:ident::$fn:ident, $symbol:literal;)*) => {
+        $(
+            $(#[$meta])*
+            #[diagnostic::on_unimplemented(
+                message = "`{Self}` can't be combined with `{Rhs}` as maybes of `{T}`",
+                label = "expected pure maybes of `{T}` on both sides",
+                note = "both sides must be pure maybes of `{T}`, that is `{T}`, `()` or `Option<{T}>`; other maybes can be turned into their pure form with `Maybe::pure`"
+            )]
+            pub trait $trait<T, Rhs>: MaybeAndThen<T, T, Rhs>
+            where
+                T: $op<Output = T>,
+                Rhs: PureMaybe<T>
+            {
+                #[doc = concat!("Returns `lhs ", $symbol, " rhs` if both maybes have a value, otherwise returns an empty maybe.")]
+                fn $fn(self, rhs: Rhs) -> <Self as MaybeAndThen<T, T, Rhs>>::Output
+                where
+                    <Self as MaybeAndThen<T, T, Rhs>>::Output: Sized;
+            }
+
+            impl<Lhs, Rhs, T> $trait<T, Rhs> for Lhs
+            where
+                T: $op<Output = T>,
+                Lhs: PureMaybe<T> + MaybeAndThen<T, T, Rhs>,
+                Rhs: PureMaybe<T>
+            {
+                fn $fn(self, rhs: Rhs) -> <Self as MaybeAndThen<T, T, Rhs>>::Output
+                where
+                    <Self as MaybeAndThen<T, T, Rhs>>::Output: Sized
+                {
+                    zip_with(self, rhs, $op::$fn)
+                }
+            }
+        )*
+    };
+}
+
+impl_maybe_arithmetic!(
+    /// Addition of pure maybes, where the kind of maybe returned follows [`MaybeAnd`](crate::ops::MaybeAnd).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::{*, ops::*};
+    /// 
+    /// assert_eq!(MaybeAdd::<i32, _>::add(Some(1), Some(2)), Some(3));
+    /// assert_eq!(MaybeAdd::<i32, _>::add(Some(1), None), None);
+    /// assert_eq!(MaybeAdd::<i32, _>::add(1, Some(2)), Some(3));
+    /// assert_eq!(MaybeAdd::<i32, _>::add(1, 2), 3);
+    /// assert_eq!(MaybeAdd::<i32, _>::add(1, ()), ());
+    /// assert_eq!(MaybeAdd::<i32, _>::add((), Some(2)), ());
+    /// ```
+    MaybeAdd: Add::add, "+";
+    /// Subtraction of pure maybes, where the kind of maybe returned follows [`MaybeAnd`](crate::ops::MaybeAnd).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::{*, ops::*};
+    /// 
+    /// assert_eq!(MaybeSub::<i32, _>::sub(Some(3), Some(2)), Some(1));
+    /// assert_eq!(MaybeSub::<i32, _>::sub(None, 2), None);
+    /// assert_eq!(MaybeSub::<i32, _>::sub(3, 2), 1);
+    /// assert_eq!(MaybeSub::<i32, _>::sub(3, ()), ());
+    /// ```
+    MaybeSub: Sub::sub, "-";
+    /// Multiplication of pure maybes, where the kind of maybe returned follows [`MaybeAnd`](crate::ops::MaybeAnd).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::{*, ops::*};
+    /// 
+    /// assert_eq!(MaybeMul::<i32, _>::mul(Some(3), Some(2)), Some(6));
+    /// assert_eq!(MaybeMul::<i32, _>::mul(Some(3), None), None);
+    /// assert_eq!(MaybeMul::<i32, _>::mul(3, 2), 6);
+    /// assert_eq!(MaybeMul::<i32, _>::mul((), 2), ());
+    /// ```
+    MaybeMul: Mul::mul, "*";
+    /// Division of pure maybes, where the kind of maybe returned follows [`MaybeAnd`](crate::ops::MaybeAnd).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::{*, ops::*};
+    /// 
+    /// assert_eq!(MaybeDiv::<i32, _>::div(Some(6), Some(2)), Some(3));
+    /// assert_eq!(MaybeDiv::<i32, _>::div(6, None), None);
+    /// assert_eq!(MaybeDiv::<i32, _>::div(6, 2), 3);
+    /// assert_eq!(MaybeDiv::<i32, _>::div(6, ()), ());
+    /// ```
+    MaybeDiv: Div::div, "/";
+);
\ No newline at end of file
@@ -1,5 +1,10 @@
 use crate::{NotVoid, PureMaybe, Maybe};
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be filtered as a pure maybe of `{T}`",
+    label = "expected `{T}`, `()` or `Option<{T}>`",
+    note = "only the pure maybes of `{T}`, that is `{T}`, `()` and `Option<{T}>`, can be filtered; other maybes can be turned into their pure form with `Maybe::pure`"
+)]
 pub trait MaybeFilter<T>: PureMaybe<T>
 {
     type Output: PureMaybe<T>;
@@ -9,20 +14,6 @@ pub trait MaybeFilter<T>: PureMaybe<T>
         F: FnOnce(&T) -> bool;
 }
 
-impl<T, Lhs> MaybeFilter<T> for Lhs
-where
-    Lhs: PureMaybe<T>
-{
-    default type Output = Option<T>;
-
-    default fn filter<F>(self, predicate: F) -> Self::Output
-    where
-        F: FnOnce(&T) -> bool
-    {
-        crate::assume_same(self.option().filter(predicate))
-    }
-}
-
 impl<T> MaybeFilter<T> for Option<T>
 {
     type Output = Option<T>;
@@ -5,5 +5,6 @@ moddef::moddef!(
         or,
         xor,
         filter,
+        arithmetic,
     }
 );
\ No newline at end of file
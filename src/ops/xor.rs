@@ -1,5 +1,10 @@
 use crate::{Maybe, NotVoid, PureMaybe};
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be combined with `{Rhs}` using `xor`",
+    label = "expected pure maybes of `{T}` on both sides",
+    note = "both sides must be pure maybes of `{T}`, that is `{T}`, `()` or `Option<{T}>`; other maybes can be turned into their pure form with `Maybe::pure`"
+)]
 pub trait MaybeXor<T, Rhs>: PureMaybe<T>
 where
     T: ?Sized,
@@ -14,24 +19,6 @@ where
         Rhs: Sized;
 }
 
-impl<Lhs, Rhs, T> MaybeXor<T, Rhs> for Lhs
-where
-    T: ?Sized,
-    Lhs: PureMaybe<T> + ?Sized,
-    Rhs: PureMaybe<T> + ?Sized
-{
-    default type Output = T;
-
-    default fn xor(self, _: Rhs) -> Self::Output
-    where
-        Self::Output: Sized,
-        Self: Sized,
-        Rhs: Sized
-    {
-        unreachable!()
-    }
-}
-
 impl<T> MaybeXor<T, Option<T>> for Option<T>
 {
     type Output = Option<T>;
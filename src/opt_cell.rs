@@ -1,6 +1,6 @@
-use core::{hash::Hash, cmp::Ordering, fmt::Debug, marker::StructuralPartialEq, ops::{Deref, DerefMut}, pin::Pin};
+use core::{hash::Hash, cmp::Ordering, fmt::Debug, iter::{Product, Sum}, marker::StructuralPartialEq, ops::{Add, Deref, DerefMut, Div, Mul, Sub}, pin::Pin};
 
-use crate::{ops::{MaybeAnd, MaybeAndThen, MaybeFilter, MaybeOr, MaybeXor}, Copied, Maybe, NotVoid, PureStaticMaybe, StaticMaybe};
+use crate::{ops::{MaybeAdd, MaybeAnd, MaybeAndThen, MaybeDiv, MaybeFilter, MaybeMul, MaybeOr, MaybeSub, MaybeXor}, Copied, Maybe, MaybeMut, NotVoid, PureMaybe, PureStaticMaybe, StaticMaybe};
 
 /// A struct containing a value of type `T`, if the constant expression `IS_SOME` evaluates to `true`.
 /// 
@@ -22,6 +22,79 @@ use crate::{ops::{MaybeAnd, MaybeAndThen, MaybeFilter, MaybeOr, MaybeXor}, Copie
 /// assert!(full.is_some());
 /// assert_eq!(full.unwrap(), ":^)");
 /// ```
+/// 
+/// # Pinning
+/// 
+/// The internal value is structurally pinned. A pinned [`OptCell`](OptCell) never moves its value, so
+/// [`OptCell::get_pin_mut`] and [`OptCell::unwrap_pin_mut`] may hand out pinned references to it, and the value is
+/// dropped in place. A cell that contains a value is [`Unpin`](core::marker::Unpin) if and only if `T` is, while an
+/// empty cell holds nothing that could be pinned, and is always [`Unpin`](core::marker::Unpin).
+/// 
+/// This means an [`OptCell`](OptCell) can be a pinned field of a struct, and projected into with
+/// [`Pin::map_unchecked_mut`](core::pin::Pin::map_unchecked_mut) or a `#[pin]`-attribute from `pin-project`.
+/// 
+/// ```rust
+/// use option_trait::*;
+/// use core::{marker::PhantomPinned, pin::Pin};
+/// 
+/// struct Task<const IS_SOME: bool>
+/// {
+///     name: &'static str,
+///     state: OptCell<(i32, PhantomPinned), IS_SOME>
+/// }
+/// 
+/// impl<const IS_SOME: bool> Task<IS_SOME>
+/// {
+///     fn state(self: Pin<&mut Self>) -> Pin<&mut OptCell<(i32, PhantomPinned), IS_SOME>>
+///     {
+///         unsafe {
+///             self.map_unchecked_mut(|this| &mut this.state)
+///         }
+///     }
+/// }
+/// 
+/// let mut task = core::pin::pin!(Task {
+///     name: "task",
+///     state: OptCell::some((1, PhantomPinned))
+/// });
+/// 
+/// task.as_mut().state().set_pinned((2, PhantomPinned));
+/// 
+/// assert_eq!(task.name, "task");
+/// assert_eq!(task.as_mut().state().unwrap_pin_mut().0, 2);
+/// ```
+/// 
+/// # Arithmetic
+/// 
+/// An [`OptCell`](OptCell) can be added, subtracted, multiplied and divided with any [`Maybe`](crate::Maybe) of the same
+/// type. The result only has a value if both sides do, and its kind follows [`MaybeAnd`](crate::ops::MaybeAnd), so
+/// anything combined with an empty maybe is empty.
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// assert_eq!(OptCell::some(1) + OptCell::some(2), OptCell::some(3));
+/// assert_eq!(OptCell::some(6) / OptCell::<i32, false>::none(), OptCell::<i32, false>::none());
+/// assert_eq!(OptCell::some(3) * 2, OptCell::some(6));
+/// assert_eq!(OptCell::some(3) - Some(2), Some(1));
+/// assert_eq!(OptCell::some(3) - (), OptCell::<i32, false>::none());
+/// ```
+/// 
+/// Iterators of [`OptCell`](OptCell)s can be summed up or multiplied together into an [`OptCell`](OptCell).
+/// If the cells are empty, the iterator is never consumed. Iterators of run-time managed maybes, which may stop short at
+/// the first empty one, are summed up with [`MaybeIteratorExt::sum_maybes`](crate::MaybeIteratorExt::sum_maybes)
+/// instead.
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// let full = [OptCell::some(1), OptCell::some(2), OptCell::some(3)];
+/// let empty = [OptCell::<i32, false>::none(); 3];
+/// 
+/// assert_eq!(full.into_iter().sum::<OptCell<i32, true>>(), OptCell::some(6));
+/// assert_eq!(full.into_iter().product::<OptCell<i32, true>>(), OptCell::some(6));
+/// assert_eq!(empty.into_iter().sum::<OptCell<i32, false>>(), OptCell::<i32, false>::none());
+/// ```
 pub struct OptCell<T, const IS_SOME: bool>(<T as private::_Spec<IS_SOME>>::Pure);
 
 impl<T, const IS_SOME: bool> NotVoid for OptCell<T, IS_SOME> {}
@@ -326,6 +399,34 @@ impl<T, const IS_SOME: bool> OptCell<T, IS_SOME>
             None
         }
     }
+    /// Replaces the pinned internal value with `value`, dropping the old value in place.
+    /// 
+    /// This works like [`Pin::set`](core::pin::Pin::set), but for the value inside the cell. If the cell is empty, `value` is dropped instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut maybe = core::pin::pin!(OptCell::some(777));
+    /// 
+    /// maybe.as_mut().set_pinned(666);
+    /// 
+    /// assert_eq!(maybe.as_ref().unwrap_pin_ref(), core::pin::pin!(666));
+    /// 
+    /// let mut empty = core::pin::pin!(OptCell::none_for(&777));
+    /// 
+    /// empty.as_mut().set_pinned(666);
+    /// 
+    /// assert!(empty.as_ref().get_pin().is_none());
+    /// ```
+    pub fn set_pinned(self: Pin<&mut Self>, value: T)
+    {
+        if let Some(mut pinned) = self.get_pin_mut()
+        {
+            pinned.set(value)
+        }
+    }
 
     /// Returns true if the cell contains a value.
     /// 
@@ -1137,28 +1238,29 @@ impl<T, const IS_SOME: bool> OptCell<T, IS_SOME>
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.and(b), ());
+    /// assert_eq!(a.and(b), OptCell::<&str, false>::none());
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.and(b), ());
+    /// assert_eq!(a.and(b), OptCell::<&str, false>::none());
     /// 
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.and(b), ());
+    /// assert_eq!(a.and(b), OptCell::<&str, false>::none());
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.and(b), "Second");
+    /// assert_eq!(a.and(b), OptCell::some("Second"));
     /// ```
-    pub fn and<Rhs>(self, other: Rhs) -> <<Self as Maybe<T>>::Pure as MaybeAnd<T, Rhs::Pure>>::Output
+    pub fn and<Rhs>(self, other: Rhs) -> <Self as Maybe<T>>::Kind<T, <<Self as Maybe<T>>::Pure as MaybeAnd<T, Rhs::Pure>>::Output>
     where
         Rhs: Maybe<T>,
         Rhs::Pure: Sized,
         (): StaticMaybe<T>,
+        <T as private::_Spec<IS_SOME>>::Pure: MaybeAnd<T, Rhs::Pure>,
         <<Self as Maybe<T>>::Pure as MaybeAnd<T, Rhs::Pure>>::Output: Sized
     {
         Maybe::and(self, other)
@@ -1178,11 +1280,12 @@ impl<T, const IS_SOME: bool> OptCell<T, IS_SOME>
     /// assert_eq!(result2, Some("abcdef"));
     /// ```
     #[doc(alias = "flatmap")]
-    pub fn and_then<U, F>(self, and_then: F) -> <<Self as Maybe<T>>::Pure as MaybeAndThen<T, U, <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure>>::Output
+    pub fn and_then<U, F>(self, and_then: F) -> <Self as Maybe<T>>::Kind<U, <<Self as Maybe<T>>::Pure as MaybeAndThen<T, U, <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure>>::Output>
     where
         F: FnOnce<(T,), Output: Maybe<U>>,
         <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure: Sized,
         (): StaticMaybe<T> + StaticMaybe<U>,
+        <T as private::_Spec<IS_SOME>>::Pure: MaybeAndThen<T, U, <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure>,
         <<Self as Maybe<T>>::Pure as MaybeAndThen<T, U, <<F as FnOnce<(T,)>>::Output as Maybe<U>>::Pure>>::Output: Sized
     {
         Maybe::and_then(self, and_then)
@@ -1203,7 +1306,7 @@ impl<T, const IS_SOME: bool> OptCell<T, IS_SOME>
     /// assert_eq!(result1, None);
     /// assert_eq!(result2, Some("abcdef"));
     /// ```
-    pub fn filter<F>(self, predicate: F) -> <<Self as Maybe<T>>::Pure as MaybeFilter<T>>::Output
+    pub fn filter<F>(self, predicate: F) -> <Self as Maybe<T>>::Kind<T, <<Self as Maybe<T>>::Pure as MaybeFilter<T>>::Output>
     where
         F: Fn(&T) -> bool,
         (): StaticMaybe<T>,
@@ -1219,28 +1322,29 @@ impl<T, const IS_SOME: bool> OptCell<T, IS_SOME>
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.or(b), ());
+    /// assert_eq!(a.or(b), OptCell::<&str, false>::none());
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.or(b), "First");
+    /// assert_eq!(a.or(b), OptCell::some("First"));
     /// 
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.or(b), "Second");
+    /// assert_eq!(a.or(b), OptCell::some("Second"));
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.or(b), "First");
+    /// assert_eq!(a.or(b), OptCell::some("First"));
     /// ```
-    pub fn or<Rhs>(self, other: Rhs) -> <<Self as Maybe<T>>::Pure as MaybeOr<T, Rhs::Pure>>::Output
+    pub fn or<Rhs>(self, other: Rhs) -> <Self as Maybe<T>>::Kind<T, <<Self as Maybe<T>>::Pure as MaybeOr<T, Rhs::Pure>>::Output>
     where
         Rhs: Maybe<T>,
         Rhs::Pure: Sized,
         (): StaticMaybe<T>,
+        <T as private::_Spec<IS_SOME>>::Pure: MaybeOr<T, Rhs::Pure>,
         <<Self as Maybe<T>>::Pure as MaybeOr<T, Rhs::Pure>>::Output: Sized
     {
         Maybe::or(self, other)
@@ -1253,27 +1357,28 @@ impl<T, const IS_SOME: bool> OptCell<T, IS_SOME>
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.or_else(|| b), ());
+    /// assert_eq!(a.or_else(|| b), OptCell::<&str, false>::none());
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.or_else(|| b), "First");
+    /// assert_eq!(a.or_else(|| b), OptCell::some("First"));
     /// 
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.or_else(|| b), "Second");
+    /// assert_eq!(a.or_else(|| b), OptCell::some("Second"));
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.or_else(|| b), "First");
+    /// assert_eq!(a.or_else(|| b), OptCell::some("First"));
     /// ```
-    pub fn or_else<F>(self, or_else: F) -> <<Self as Maybe<T>>::Pure as MaybeOr<T, <<F as FnOnce<()>>::Output as Maybe<T>>::Pure>>::Output
+    pub fn or_else<F>(self, or_else: F) -> <Self as Maybe<T>>::Kind<T, <<Self as Maybe<T>>::Pure as MaybeOr<T, <<F as FnOnce<()>>::Output as Maybe<T>>::Pure>>::Output>
     where
         F: FnOnce<(), Output: Maybe<T, Pure: Sized>>,
         (): StaticMaybe<T>,
+        <T as private::_Spec<IS_SOME>>::Pure: MaybeOr<T, <<F as FnOnce<()>>::Output as Maybe<T>>::Pure>,
         <<Self as Maybe<T>>::Pure as MaybeOr<T, <<F as FnOnce<()>>::Output as Maybe<T>>::Pure>>::Output: Sized
     {
         Maybe::or_else(self, or_else)
@@ -1286,28 +1391,29 @@ impl<T, const IS_SOME: bool> OptCell<T, IS_SOME>
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.xor(b), ());
+    /// assert_eq!(a.xor(b), OptCell::<&str, false>::none());
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::<&'static str, false>::none();
     /// 
-    /// assert_eq!(a.xor(b), "First");
+    /// assert_eq!(a.xor(b), OptCell::some("First"));
     /// 
     /// let a = OptCell::<&'static str, false>::none();
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.xor(b), "Second");
+    /// assert_eq!(a.xor(b), OptCell::some("Second"));
     /// 
     /// let a = OptCell::some("First");
     /// let b = OptCell::some("Second");
     /// 
-    /// assert_eq!(a.xor(b), ());
+    /// assert_eq!(a.xor(b), OptCell::<&str, false>::none());
     /// ```
-    pub fn xor<Rhs>(self, other: Rhs) -> <<Self as Maybe<T>>::Pure as MaybeXor<T, Rhs::Pure>>::Output
+    pub fn xor<Rhs>(self, other: Rhs) -> <Self as Maybe<T>>::Kind<T, <<Self as Maybe<T>>::Pure as MaybeXor<T, Rhs::Pure>>::Output>
     where
         Rhs: Maybe<T>,
         Rhs::Pure: Sized,
         (): StaticMaybe<T>,
+        <T as private::_Spec<IS_SOME>>::Pure: MaybeXor<T, Rhs::Pure>,
         <<Self as Maybe<T>>::Pure as MaybeXor<T, Rhs::Pure>>::Output: Sized
     {
         Maybe::xor(self, other)
@@ -1633,6 +1739,59 @@ where
     }
 }
 
+macro_rules! impl_arithmetic {
+    ($($op:ident::$fn:ident => $maybe_op:ident;)*) => {
+        $(
+            impl<T, Rhs, const IS_SOME: bool> $op<Rhs> for OptCell<T, IS_SOME>
+            where
+                T: $op<Output = T>,
+                Rhs: Maybe<T>,
+                Rhs::Pure: Sized,
+                (): StaticMaybe<T>,
+                <T as private::_Spec<IS_SOME>>::Pure: $maybe_op<T, Rhs::Pure>,
+                <<Self as Maybe<T>>::Pure as MaybeAndThen<T, T, Rhs::Pure>>::Output: Sized
+            {
+                type Output = <Self as Maybe<T>>::Kind<T, <<Self as Maybe<T>>::Pure as MaybeAndThen<T, T, Rhs::Pure>>::Output>;
+
+                fn $fn(self, rhs: Rhs) -> Self::Output
+                {
+                    <Self as Maybe<T>>::from_pure($maybe_op::$fn(Maybe::<T>::pure(self), rhs.pure()))
+                }
+            }
+        )*
+    };
+}
+
+impl_arithmetic!(
+    Add::add => MaybeAdd;
+    Sub::sub => MaybeSub;
+    Mul::mul => MaybeMul;
+    Div::div => MaybeDiv;
+);
+
+impl<T, U, const IS_SOME: bool> Sum<OptCell<U, IS_SOME>> for OptCell<T, IS_SOME>
+where
+    T: Sum<U>
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = OptCell<U, IS_SOME>>
+    {
+        Self::from_fn(|| iter.map(OptCell::unwrap).sum())
+    }
+}
+impl<T, U, const IS_SOME: bool> Product<OptCell<U, IS_SOME>> for OptCell<T, IS_SOME>
+where
+    T: Product<U>
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = OptCell<U, IS_SOME>>
+    {
+        Self::from_fn(|| iter.map(OptCell::unwrap).product())
+    }
+}
+
 impl<T> const Deref for OptCell<T, true>
 {
     type Target = T;
@@ -1704,6 +1863,14 @@ impl<T, const IS_SOME: bool> Maybe<T> for OptCell<T, IS_SOME>
     where
         T: Copied,
         (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = <T as private::_Spec<IS_SOME>>::MappedMaybe<U, M>
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = <P as private::_Kind<U>>::Output
+    where
+        P: PureMaybe<U>;
 
     fn is_some(&self) -> bool
     {
@@ -1861,6 +2028,20 @@ impl<T, const IS_SOME: bool> Maybe<T> for OptCell<T, IS_SOME>
     {
         self.map(map)
     }
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        if !IS_SOME
+        {
+            return crate::assume_same(OptCell::<U, false>::none())
+        }
+        crate::assume_same(map(self.unwrap()))
+    }
     fn map_or<U, F>(self, default: U, map: F) -> U
     where
         F: FnOnce(T) -> U,
@@ -1989,6 +2170,46 @@ impl<T, const IS_SOME: bool> Maybe<T> for OptCell<T, IS_SOME>
             self.map_unchecked_mut(|this| &mut this.0)
         })
     }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        private::_Kind::<U>::into_cell(pure)
+    }
+}
+
+impl<T, const IS_SOME: bool> crate::private::_Rewrap<T> for OptCell<T, IS_SOME>
+{
+    fn try_rewrap(option: Option<T>) -> Result<Self, Option<T>>
+    {
+        if option.is_some() != IS_SOME
+        {
+            return Err(option)
+        }
+        Ok(Self::from_fn(|| option.unwrap()))
+    }
+    fn rewrap<M>(maybe: M) -> Self
+    where
+        M: Maybe<T>
+    {
+        Self::from_fn(|| maybe.unwrap())
+    }
+}
+
+impl<T> MaybeMut<T> for OptCell<T, true>
+{
+    fn insert(&mut self, value: T) -> &mut T
+    {
+        let x = self.as_value_mut();
+        *x = value;
+        x
+    }
+    fn get_or_insert_with<F>(&mut self, _: F) -> &mut T
+    where
+        F: FnOnce() -> T
+    {
+        self.as_value_mut()
+    }
 }
 
 impl<T, const IS_SOME: bool> /*const*/ StaticMaybe<T> for OptCell<T, IS_SOME>
@@ -2047,10 +2268,55 @@ where
 
 mod private
 {
-    use crate::{PureStaticMaybe, StaticMaybe};
+    use crate::{Maybe, NotVoid, PureStaticMaybe, StaticMaybe};
 
     use super::OptCell;
 
+    pub trait _Kind<U>
+    {
+        type Output;
+
+        fn into_cell(self) -> Self::Output;
+    }
+    impl<U, P> _Kind<U> for P
+    {
+        default type Output = P;
+
+        default fn into_cell(self) -> Self::Output
+        {
+            crate::assume_same(self)
+        }
+    }
+    impl<U> _Kind<U> for U
+    {
+        type Output = OptCell<U, true>;
+
+        fn into_cell(self) -> Self::Output
+        {
+            OptCell::some(self)
+        }
+    }
+    impl<U> _Kind<U> for ()
+    where
+        U: NotVoid
+    {
+        type Output = OptCell<U, false>;
+
+        fn into_cell(self) -> Self::Output
+        {
+            OptCell::none()
+        }
+    }
+    impl<U> _Kind<U> for Option<U>
+    {
+        type Output = Option<U>;
+
+        fn into_cell(self) -> Self::Output
+        {
+            self
+        }
+    }
+
     pub trait _Spec<const IS_SOME: bool>
     {
         type Opposite: StaticMaybe<Self>;
@@ -2063,6 +2329,11 @@ mod private
             M: ?Sized,
             O: ?Sized;
         type Pure: PureStaticMaybe<Self>;
+        type MappedMaybe<U, M>: Maybe<U>
+        where
+            M: Maybe<U>,
+            U: StaticMaybe<U>,
+            (): StaticMaybe<U>;
     }
     impl<T, const IS_SOME: bool> _Spec<IS_SOME> for T
     {
@@ -2076,6 +2347,11 @@ mod private
             M: ?Sized,
             O: ?Sized;
         default type Pure = T;
+        default type MappedMaybe<U, M> = M
+        where
+            M: Maybe<U>,
+            U: StaticMaybe<U>,
+            (): StaticMaybe<U>;
     }
     impl<T> _Spec<false> for T
     where
@@ -2091,6 +2367,11 @@ mod private
             M: ?Sized,
             O: ?Sized;
         type Pure = ();
+        type MappedMaybe<U, M> = OptCell<U, false>
+        where
+            M: Maybe<U>,
+            U: StaticMaybe<U>,
+            (): StaticMaybe<U>;
     }
     impl<T> _Spec<true> for T
     where
@@ -2106,14 +2387,28 @@ mod private
             M: ?Sized,
             O: ?Sized;
         type Pure = T;
+        type MappedMaybe<U, M> = M
+        where
+            M: Maybe<U>,
+            U: StaticMaybe<U>,
+            (): StaticMaybe<U>;
     }
 }
 
 #[cfg(test)]
 mod test
 {
+    use core::marker::PhantomPinned;
+
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+
     use super::OptCell;
 
+    assert_impl_all!(OptCell<i32, true>: Unpin);
+    assert_impl_all!(OptCell<i32, false>: Unpin);
+    assert_impl_all!(OptCell<PhantomPinned, false>: Unpin);
+    assert_not_impl_any!(OptCell<PhantomPinned, true>: Unpin);
+
     #[test]
     fn it_works()
     {
@@ -2123,4 +2418,68 @@ mod test
         println!("{:?}", maybe);
         println!("{:?}", empty);
     }
+
+    #[test]
+    fn pinned()
+    {
+        use core::{cell::Cell, marker::PhantomPinned, pin::{pin, Pin}};
+
+        struct Tracked<'a>
+        {
+            drops: &'a Cell<usize>,
+            _pin: PhantomPinned
+        }
+
+        impl Drop for Tracked<'_>
+        {
+            fn drop(&mut self)
+            {
+                self.drops.set(self.drops.get() + 1)
+            }
+        }
+
+        let drops = Cell::new(0);
+        let tracked = || Tracked {
+            drops: &drops,
+            _pin: PhantomPinned
+        };
+
+        {
+            let mut maybe = pin!(OptCell::some(tracked()));
+
+            let address = &*maybe.as_mut().unwrap_pin_mut() as *const Tracked;
+            assert_eq!(maybe.as_ref().get_pin().map(|pinned| &*pinned as *const Tracked), Some(address));
+            assert_eq!(&*maybe.as_ref().as_pin_ref().unwrap() as *const Tracked, address);
+
+            maybe.as_mut().set_pinned(tracked());
+            assert_eq!(drops.get(), 1);
+            assert_eq!(&*maybe.as_mut().unwrap_pin_mut() as *const Tracked, address);
+        }
+        assert_eq!(drops.get(), 2);
+
+        {
+            let mut empty: Pin<&mut OptCell<Tracked, false>> = pin!(OptCell::none());
+
+            assert!(empty.as_mut().get_pin_mut().is_none());
+            empty.as_mut().set_pinned(tracked());
+            assert_eq!(drops.get(), 3);
+        }
+        assert_eq!(drops.get(), 3);
+    }
+    #[test]
+    fn sum_short_circuits()
+    {
+        let mut consumed = 0;
+        let empty: OptCell<i32, false> = core::iter::repeat_with(|| {
+            consumed += 1;
+            OptCell::<i32, false>::none()
+        }).take(3).sum();
+
+        assert_eq!(empty, OptCell::<i32, false>::none());
+        assert_eq!(consumed, 0);
+
+        let full: OptCell<i32, true> = (1..=4).map(OptCell::some).product();
+
+        assert_eq!(full, OptCell::some(24));
+    }
 }
\ No newline at end of file
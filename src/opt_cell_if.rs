@@ -0,0 +1,262 @@
+use core::marker::PhantomData;
+
+use crate::{OptCell, StaticMaybe};
+
+/// A compile-time predicate on the type `T`.
+/// 
+/// This is used by [`OptCellIf`] to decide whether or not the cell contains a value, based on its payload type.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use option_trait::*;
+/// 
+/// trait HasFeature
+/// {
+///     const ENABLED: bool;
+/// }
+/// impl HasFeature for u8
+/// {
+///     const ENABLED: bool = true;
+/// }
+/// impl HasFeature for u16
+/// {
+///     const ENABLED: bool = false;
+/// }
+/// 
+/// struct Enabled;
+/// 
+/// impl<T> ConstPredicate<T> for Enabled
+/// where
+///     T: HasFeature
+/// {
+///     const VALUE: bool = T::ENABLED;
+/// }
+/// 
+/// let enabled: OptCellIf<u8, Enabled> = OptCell::some(1);
+/// let disabled: OptCellIf<u16, Enabled> = OptCell::none();
+/// 
+/// assert!(enabled.is_some());
+/// assert!(disabled.is_none());
+/// ```
+pub trait ConstPredicate<T>
+where
+    T: ?Sized
+{
+    /// Equals `true` if the predicate holds for `T`.
+    const VALUE: bool;
+}
+
+/// Picks the [`OptCell`](crate::OptCell) that contains a `T` only if the predicate holds for `T`.
+/// 
+/// This is implemented for every [`ConstPredicate`], and is what [`OptCellIf`] is resolved with. Use this as the bound
+/// in generic code that names an [`OptCellIf`].
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use option_trait::{*, opt_cell_if::NonZeroSized};
+/// 
+/// struct Field<T, P>
+/// where
+///     P: SelectOptCell<T>
+/// {
+///     value: OptCellIf<T, P>
+/// }
+/// 
+/// fn count<T, P>(_: &Field<T, P>) -> usize
+/// where
+///     P: SelectOptCell<T>
+/// {
+///     <OptCellIf<T, P> as StaticMaybe<T>>::COUNT
+/// }
+/// 
+/// let field = Field::<u8, NonZeroSized> {
+///     value: OptCell::some(1)
+/// };
+/// 
+/// assert_eq!(count(&field), 1);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a predicate on `{T}`",
+    label = "expected a `ConstPredicate<{T}>`",
+    note = "`SelectOptCell<{T}>` is implemented for every `ConstPredicate<{T}>`"
+)]
+pub trait SelectOptCell<T>: ConstPredicate<T>
+{
+    /// Either `OptCell<T, true>` or `OptCell<T, false>`, depending on the predicate.
+    type OptCell: StaticMaybe<T>;
+}
+impl<T, P> SelectOptCell<T> for P
+where
+    P: ConstPredicate<T> + ?Sized,
+    [(); <P as ConstPredicate<T>>::VALUE as usize]:
+{
+    type OptCell = OptCell<T, { <P as ConstPredicate<T>>::VALUE }>;
+}
+
+/// An [`OptCell`](crate::OptCell) that contains a value only if the predicate `P` holds for `T`.
+/// 
+/// This lets a field disappear automatically for certain payloads, without writing out the constant expression.
+/// The crate naming it must enable the `generic_const_exprs` feature, since the predicate is evaluated there.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// #![feature(generic_const_exprs)]
+/// 
+/// use option_trait::{*, opt_cell_if::{NonZeroSized, Not}};
+/// use static_assertions::*;
+/// 
+/// assert_type_eq_all!(OptCellIf<u8, NonZeroSized>, OptCell<u8, true>);
+/// assert_type_eq_all!(OptCellIf<(), NonZeroSized>, OptCell<(), false>);
+/// assert_type_eq_all!(OptCellIf<(), Not<NonZeroSized>>, OptCell<(), true>);
+/// ```
+pub type OptCellIf<T, P> = <P as SelectOptCell<T>>::OptCell;
+
+/// Holds if `T` is not zero-sized.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::{*, opt_cell_if::NonZeroSized};
+/// 
+/// assert!(<NonZeroSized as ConstPredicate<u8>>::VALUE);
+/// assert!(!<NonZeroSized as ConstPredicate<()>>::VALUE);
+/// ```
+pub struct NonZeroSized;
+
+impl<T> ConstPredicate<T> for NonZeroSized
+{
+    const VALUE: bool = core::mem::size_of::<T>() != 0;
+}
+
+/// Holds if `T` needs to be dropped, as told by [`needs_drop`](core::mem::needs_drop).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::{*, opt_cell_if::NeedsDrop};
+/// 
+/// assert!(<NeedsDrop as ConstPredicate<String>>::VALUE);
+/// assert!(!<NeedsDrop as ConstPredicate<u8>>::VALUE);
+/// ```
+pub struct NeedsDrop;
+
+impl<T> ConstPredicate<T> for NeedsDrop
+{
+    const VALUE: bool = core::mem::needs_drop::<T>();
+}
+
+/// Holds if `P` does not hold.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::{*, opt_cell_if::{NonZeroSized, Not}};
+/// 
+/// assert!(<Not<NonZeroSized> as ConstPredicate<()>>::VALUE);
+/// assert!(!<Not<NonZeroSized> as ConstPredicate<u8>>::VALUE);
+/// ```
+pub struct Not<P>(PhantomData<P>)
+where
+    P: ?Sized;
+
+impl<T, P> ConstPredicate<T> for Not<P>
+where
+    T: ?Sized,
+    P: ConstPredicate<T> + ?Sized
+{
+    const VALUE: bool = !P::VALUE;
+}
+
+/// Holds if both `A` and `B` hold.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::{*, opt_cell_if::{And, NeedsDrop, NonZeroSized}};
+/// 
+/// assert!(<And<NonZeroSized, NeedsDrop> as ConstPredicate<String>>::VALUE);
+/// assert!(!<And<NonZeroSized, NeedsDrop> as ConstPredicate<u8>>::VALUE);
+/// ```
+pub struct And<A, B>(PhantomData<A>, PhantomData<B>)
+where
+    A: ?Sized,
+    B: ?Sized;
+
+impl<T, A, B> ConstPredicate<T> for And<A, B>
+where
+    T: ?Sized,
+    A: ConstPredicate<T> + ?Sized,
+    B: ConstPredicate<T> + ?Sized
+{
+    const VALUE: bool = A::VALUE && B::VALUE;
+}
+
+/// Holds if either `A` or `B` holds.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::{*, opt_cell_if::{NeedsDrop, NonZeroSized, Not, Or}};
+/// 
+/// assert!(<Or<Not<NonZeroSized>, NeedsDrop> as ConstPredicate<()>>::VALUE);
+/// assert!(!<Or<Not<NonZeroSized>, NeedsDrop> as ConstPredicate<u8>>::VALUE);
+/// ```
+pub struct Or<A, B>(PhantomData<A>, PhantomData<B>)
+where
+    A: ?Sized,
+    B: ?Sized;
+
+impl<T, A, B> ConstPredicate<T> for Or<A, B>
+where
+    T: ?Sized,
+    A: ConstPredicate<T> + ?Sized,
+    B: ConstPredicate<T> + ?Sized
+{
+    const VALUE: bool = A::VALUE || B::VALUE;
+}
+
+#[cfg(test)]
+mod test
+{
+    use static_assertions::assert_type_eq_all;
+
+    use super::{And, NeedsDrop, NonZeroSized, Not, OptCellIf, Or, SelectOptCell};
+    use crate::{OptCell, StaticMaybe};
+
+    #[test]
+    fn common_predicates()
+    {
+        assert_type_eq_all!(OptCellIf<u32, NonZeroSized>, OptCell<u32, true>);
+        assert_type_eq_all!(OptCellIf<[u32; 0], NonZeroSized>, OptCell<[u32; 0], false>);
+        assert_type_eq_all!(OptCellIf<String, NeedsDrop>, OptCell<String, true>);
+        assert_type_eq_all!(OptCellIf<&str, NeedsDrop>, OptCell<&str, false>);
+        assert_type_eq_all!(OptCellIf<(), Not<NonZeroSized>>, OptCell<(), true>);
+        assert_type_eq_all!(OptCellIf<Vec<u8>, And<NonZeroSized, NeedsDrop>>, OptCell<Vec<u8>, true>);
+        assert_type_eq_all!(OptCellIf<u8, And<NonZeroSized, NeedsDrop>>, OptCell<u8, false>);
+        assert_type_eq_all!(OptCellIf<u8, Or<NonZeroSized, NeedsDrop>>, OptCell<u8, true>);
+        assert_type_eq_all!(OptCellIf<(), Or<NonZeroSized, NeedsDrop>>, OptCell<(), false>);
+    }
+
+    #[test]
+    fn field_disappears()
+    {
+        struct Payload<T>
+        where
+            NonZeroSized: SelectOptCell<T>
+        {
+            _id: u64,
+            _extra: OptCellIf<T, NonZeroSized>
+        }
+
+        assert_eq!(core::mem::size_of::<Payload<()>>(), core::mem::size_of::<u64>());
+        assert_eq!(core::mem::size_of::<Payload<u64>>(), 2*core::mem::size_of::<u64>());
+        assert_eq!(<OptCellIf<u64, NonZeroSized> as StaticMaybe<u64>>::COUNT, 1);
+    }
+}
\ No newline at end of file
@@ -6,6 +6,11 @@ use crate::{private, PureMaybe};
 /// 
 /// This is a run-time managed [`Maybe`](crate::Maybe). Wether or not it contains a value can be decided at run-time.
 //#[const_trait]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an `Option`",
+    label = "expected an `Option`",
+    note = "only `Option<T>` implements `Optional`; use `Maybe<T>` to also accept other maybes"
+)]
 pub trait Optional: private::Optional + PureMaybe<Self::Some> + From<Option<Self::Some>> + Into<Option<Self::Some>> + StructuralPartialEq
 {
     /// The internal type that may or may not exist inside the option.
@@ -0,0 +1,897 @@
+use core::{fmt::Debug, hash::Hash, ops::{Deref, DerefMut}, pin::Pin};
+
+use crate::{Copied, Maybe, OptCell, Optional, PureMaybe, StaticMaybe};
+
+/// A run-time managed maybe that tells apart a value that is absent, a value that is explicitly set to null and a value that
+/// is set to something.
+/// 
+/// This is the shape of a field in a JSON Merge Patch, where leaving out a field keeps it as it is, setting it to `null`
+/// clears it, and setting it to anything else replaces it. As a [`Maybe`](Maybe), it only contains a value if it's
+/// [`Patch::Value`](Patch::Value).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// let mut name = Some("Name");
+/// 
+/// Patch::Absent.apply_to(&mut name);
+/// assert_eq!(name, Some("Name"));
+/// 
+/// Patch::Value("New name").apply_to(&mut name);
+/// assert_eq!(name, Some("New name"));
+/// 
+/// Patch::Null.apply_to(&mut name);
+/// assert_eq!(name, None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum Patch<T>
+{
+    #[default]
+    Absent,
+    Null,
+    Value(T)
+}
+
+impl<T> Patch<T>
+{
+    /// Returns true if the patch leaves the value as it is.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(Patch::<i32>::Absent.is_absent());
+    /// assert!(!Patch::<i32>::Null.is_absent());
+    /// assert!(!Patch::Value(777).is_absent());
+    /// ```
+    pub const fn is_absent(&self) -> bool
+    {
+        matches!(self, Patch::Absent)
+    }
+    /// Returns true if the patch explicitly clears the value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(!Patch::<i32>::Absent.is_null());
+    /// assert!(Patch::<i32>::Null.is_null());
+    /// assert!(!Patch::Value(777).is_null());
+    /// ```
+    pub const fn is_null(&self) -> bool
+    {
+        matches!(self, Patch::Null)
+    }
+    /// Returns true if the patch sets the value to something.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(!Patch::<i32>::Absent.is_value());
+    /// assert!(!Patch::<i32>::Null.is_value());
+    /// assert!(Patch::Value(777).is_value());
+    /// ```
+    pub const fn is_value(&self) -> bool
+    {
+        matches!(self, Patch::Value(_))
+    }
+
+    /// Borrows the value of the patch, if it has one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let patch = Patch::Value(String::from("Value"));
+    /// 
+    /// assert_eq!(patch.as_ref(), Patch::Value(&String::from("Value")));
+    /// ```
+    pub const fn as_ref(&self) -> Patch<&T>
+    {
+        match self
+        {
+            Patch::Absent => Patch::Absent,
+            Patch::Null => Patch::Null,
+            Patch::Value(value) => Patch::Value(value)
+        }
+    }
+    /// Mutably borrows the value of the patch, if it has one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut patch = Patch::Value(777);
+    /// 
+    /// if let Patch::Value(value) = patch.as_mut()
+    /// {
+    ///     *value += 1;
+    /// }
+    /// 
+    /// assert_eq!(patch, Patch::Value(778));
+    /// ```
+    pub const fn as_mut(&mut self) -> Patch<&mut T>
+    {
+        match self
+        {
+            Patch::Absent => Patch::Absent,
+            Patch::Null => Patch::Null,
+            Patch::Value(value) => Patch::Value(value)
+        }
+    }
+
+    /// Maps the value of the patch, if it has one, while keeping absent and null patches as they are.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(Patch::Value(777).map(|value| value + 1), Patch::Value(778));
+    /// assert_eq!(Patch::<i32>::Null.map(|value| value + 1), Patch::Null);
+    /// assert_eq!(Patch::<i32>::Absent.map(|value| value + 1), Patch::Absent);
+    /// ```
+    pub fn map<U, F>(self, map: F) -> Patch<U>
+    where
+        F: FnOnce(T) -> U
+    {
+        match self
+        {
+            Patch::Absent => Patch::Absent,
+            Patch::Null => Patch::Null,
+            Patch::Value(value) => Patch::Value(map(value))
+        }
+    }
+    /// Returns the value of the patch, if it has one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(Patch::Value(777).value(), Some(777));
+    /// assert_eq!(Patch::<i32>::Null.value(), None);
+    /// assert_eq!(Patch::<i32>::Absent.value(), None);
+    /// ```
+    pub fn value(self) -> Option<T>
+    {
+        match self
+        {
+            Patch::Value(value) => Some(value),
+            Patch::Absent | Patch::Null => None
+        }
+    }
+
+    /// Applies the patch to an optional value.
+    /// 
+    /// An absent patch leaves the target as it is, a null patch takes the value out of the target, and a patch with a
+    /// value inserts it into the target.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut target = None;
+    /// 
+    /// Patch::Value(777).apply_to(&mut target);
+    /// assert_eq!(target, Some(777));
+    /// 
+    /// Patch::Absent.apply_to(&mut target);
+    /// assert_eq!(target, Some(777));
+    /// 
+    /// Patch::Null.apply_to(&mut target);
+    /// assert_eq!(target, None);
+    /// ```
+    pub fn apply_to<O>(self, target: &mut O)
+    where
+        O: Optional<Some = T>
+    {
+        match self
+        {
+            Patch::Absent => (),
+            Patch::Null => {
+                target.take();
+            },
+            Patch::Value(value) => {
+                target.insert(value);
+            }
+        }
+    }
+}
+
+impl<T> From<Option<Option<T>>> for Patch<T>
+{
+    fn from(value: Option<Option<T>>) -> Self
+    {
+        match value
+        {
+            None => Patch::Absent,
+            Some(None) => Patch::Null,
+            Some(Some(value)) => Patch::Value(value)
+        }
+    }
+}
+impl<T> From<Patch<T>> for Option<Option<T>>
+{
+    fn from(value: Patch<T>) -> Self
+    {
+        match value
+        {
+            Patch::Absent => None,
+            Patch::Null => Some(None),
+            Patch::Value(value) => Some(Some(value))
+        }
+    }
+}
+
+impl<T> Maybe<T> for Patch<T>
+{
+    const IS_MAYBE_SOME: bool = true;
+    const IS_MAYBE_NONE: bool = true;
+
+    type Pure = Option<T>
+    where
+        T: StaticMaybe<T>,
+        (): StaticMaybe<T>;
+    type PureRef<'a> = <Self::AsRef<'a> as Maybe<&'a T>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PureMut<'a> = <Self::AsMut<'a> as Maybe<&'a mut T>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PurePinRef<'a> = <Self::AsPinRef<'a> as Maybe<Pin<&'a T>>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+    type PurePinMut<'a> = <Self::AsPinMut<'a> as Maybe<Pin<&'a mut T>>>::Pure
+    where
+        Self: 'a,
+        T: 'a;
+
+    type Mapped<U> = Option<U>
+    where
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Copied = Self::Mapped<<T as Copied>::Output>
+    where
+        T: Copied,
+        (): StaticMaybe<<T as Copied>::Output>;
+    type MappedMaybe<U, M> = Option<U>
+    where
+        M: Maybe<U>,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>;
+    type Kind<U, P> = P
+    where
+        P: PureMaybe<U>;
+
+    fn is_some(&self) -> bool
+    {
+        self.is_value()
+    }
+    fn is_none(&self) -> bool
+    {
+        !self.is_value()
+    }
+    fn as_ref<'a>(&'a self) -> Self::AsRef<'a>
+    where
+        T: 'a
+    {
+        self.as_ref().value()
+    }
+    fn as_mut<'a>(&'a mut self) -> Self::AsMut<'a>
+    where
+        T: 'a
+    {
+        self.as_mut().value()
+    }
+    fn as_pin_ref<'a>(self: Pin<&'a Self>) -> Self::AsPinRef<'a>
+    where
+        T: 'a
+    {
+        self.get_ref()
+            .as_ref()
+            .value()
+            .map(|value| unsafe {
+                Pin::new_unchecked(value)
+            })
+    }
+    fn as_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::AsPinMut<'a>
+    where
+        T: 'a
+    {
+        unsafe {
+            self.get_unchecked_mut()
+                .as_mut()
+                .value()
+                .map(|value| Pin::new_unchecked(value))
+        }
+    }
+    fn as_slice(&self) -> &[T]
+    where
+        T: Sized
+    {
+        match self
+        {
+            Patch::Value(value) => core::slice::from_ref(value),
+            Patch::Absent | Patch::Null => &[]
+        }
+    }
+    fn as_mut_slice(&mut self) -> &mut [T]
+    where
+        T: Sized
+    {
+        match self
+        {
+            Patch::Value(value) => core::slice::from_mut(value),
+            Patch::Absent | Patch::Null => &mut []
+        }
+    }
+    fn expect(self, msg: &str) -> T
+    where
+        T: Sized
+    {
+        match self
+        {
+            Patch::Value(value) => value,
+            Patch::Absent | Patch::Null => crate::on_unwrap_empty_msg(msg)
+        }
+    }
+    fn unwrap(self) -> T
+    where
+        T: Sized
+    {
+        match self
+        {
+            Patch::Value(value) => value,
+            Patch::Absent | Patch::Null => crate::on_unwrap_empty()
+        }
+    }
+    fn unwrap_ref(&self) -> &T
+    {
+        Maybe::<&T>::unwrap(Maybe::<T>::as_ref(self))
+    }
+    fn unwrap_mut(&mut self) -> &mut T
+    {
+        Maybe::<&mut T>::unwrap(Maybe::<T>::as_mut(self))
+    }
+    fn unwrap_pin_ref<'a>(self: Pin<&'a Self>) -> Pin<&'a T>
+    where
+        T: 'a
+    {
+        Maybe::<Pin<&T>>::unwrap(Maybe::<T>::as_pin_ref(self))
+    }
+    fn unwrap_pin_mut<'a>(self: Pin<&'a mut Self>) -> Pin<&'a mut T>
+    where
+        T: 'a
+    {
+        Maybe::<Pin<&mut T>>::unwrap(Maybe::<T>::as_pin_mut(self))
+    }
+    fn unwrap_or(self, default: T) -> T
+    where
+        T: Sized
+    {
+        self.value().unwrap_or(default)
+    }
+    fn unwrap_ref_or<'a>(&'a self, default: &'a T) -> &'a T
+    where
+        T: 'a
+    {
+        self.as_ref().value().unwrap_or(default)
+    }
+    fn unwrap_mut_or<'a>(&'a mut self, default: &'a mut T) -> &'a mut T
+    where
+        T: 'a
+    {
+        self.as_mut().value().unwrap_or(default)
+    }
+    fn unwrap_pin_ref_or<'a>(self: Pin<&'a Self>, default: Pin<&'a T>) -> Pin<&'a T>
+    where
+        T: 'a
+    {
+        Maybe::<T>::as_pin_ref(self).unwrap_or(default)
+    }
+    fn unwrap_pin_mut_or<'a>(self: Pin<&'a mut Self>, default: Pin<&'a mut T>) -> Pin<&'a mut T>
+    where
+        T: 'a
+    {
+        Maybe::<T>::as_pin_mut(self).unwrap_or(default)
+    }
+    fn unwrap_or_else<F>(self, default: F) -> T
+    where
+        F: FnOnce() -> T,
+        T: Sized
+    {
+        self.value().unwrap_or_else(default)
+    }
+    fn unwrap_ref_or_else<'a, F>(&'a self, default: F) -> &'a T
+    where
+        F: FnOnce() -> &'a T,
+        T: 'a
+    {
+        self.as_ref().value().unwrap_or_else(default)
+    }
+    fn unwrap_mut_or_else<'a, F>(&'a mut self, default: F) -> &'a mut T
+    where
+        F: FnOnce() -> &'a mut T,
+        T: 'a
+    {
+        self.as_mut().value().unwrap_or_else(default)
+    }
+    fn unwrap_pin_ref_or_else<'a, F>(self: Pin<&'a Self>, default: F) -> Pin<&'a T>
+    where
+        F: FnOnce() -> Pin<&'a T>,
+        T: 'a
+    {
+        Maybe::<T>::as_pin_ref(self).unwrap_or_else(default)
+    }
+    fn unwrap_pin_mut_or_else<'a, F>(self: Pin<&'a mut Self>, default: F) -> Pin<&'a mut T>
+    where
+        F: FnOnce() -> Pin<&'a mut T>,
+        T: 'a
+    {
+        Maybe::<T>::as_pin_mut(self).unwrap_or_else(default)
+    }
+    fn unwrap_or_default(self) -> T
+    where
+        T: Sized + Default
+    {
+        self.value().unwrap_or_default()
+    }
+    fn map<U, F>(self, map: F) -> Self::Mapped<U>
+    where
+        F: FnOnce(T) -> U,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        self.value().map(map)
+    }
+    fn map_maybe<U, M, F>(self, map: F) -> Self::MappedMaybe<U, M>
+    where
+        F: FnOnce(T) -> M,
+        M: Maybe<U>,
+        T: Sized,
+        U: StaticMaybe<U>,
+        (): StaticMaybe<U>
+    {
+        self.value()
+            .and_then(|value| map(value).option())
+    }
+    fn map_or<U, F>(self, default: U, map: F) -> U
+    where
+        F: FnOnce(T) -> U,
+        T: Sized
+    {
+        self.value().map_or(default, map)
+    }
+    fn map_or_else<U, D, F>(self, default: D, map: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(T) -> U,
+        T: Sized
+    {
+        self.value().map_or_else(default, map)
+    }
+    fn ok_or<F>(self, error: F) -> Result<T, F>
+    where
+        T: Sized
+    {
+        self.value().ok_or(error)
+    }
+    fn ok_or_else<F, O>(self, error: O) -> Result<T, F>
+    where
+        O: FnOnce() -> F,
+        T: Sized
+    {
+        self.value().ok_or_else(error)
+    }
+    fn as_deref<'a>(&'a self) -> Self::AsDeref<'a>
+    where
+        T: Deref + 'a
+    {
+        self.as_ref()
+            .value()
+            .map(Deref::deref)
+    }
+    fn as_deref_mut<'a>(&'a mut self) -> Self::AsDerefMut<'a>
+    where
+        T: DerefMut + 'a
+    {
+        self.as_mut()
+            .value()
+            .map(DerefMut::deref_mut)
+    }
+    fn copied(&self) -> Self::Copied
+    where
+        T: Copied<Output: Copy>,
+        (): StaticMaybe<<T as Copied>::Output>
+    {
+        self.as_ref()
+            .value()
+            .map(crate::copy_ref)
+    }
+    fn cloned(&self) -> Self::Copied
+    where
+        T: Copied<Output: Clone>,
+        T: Sized,
+        (): StaticMaybe<<T as Copied>::Output>
+    {
+        self.as_ref()
+            .value()
+            .map(crate::clone_ref)
+    }
+
+    fn option(self) -> Option<T>
+    {
+        self.value()
+    }
+    fn option_ref(&self) -> Option<&T>
+    {
+        self.as_ref().value()
+    }
+    fn option_mut(&mut self) -> Option<&mut T>
+    {
+        self.as_mut().value()
+    }
+    fn option_pin_ref(self: Pin<&Self>) -> Option<Pin<&T>>
+    {
+        Maybe::<T>::as_pin_ref(self)
+    }
+    fn option_pin_mut(self: Pin<&mut Self>) -> Option<Pin<&mut T>>
+    {
+        Maybe::<T>::as_pin_mut(self)
+    }
+
+    fn pure(self) -> Self::Pure
+    where
+        T: StaticMaybe<T>,
+        (): StaticMaybe<T>
+    {
+        self.value()
+    }
+    fn pure_ref<'a>(&'a self) -> Self::PureRef<'a>
+    where
+        T: 'a
+    {
+        self.as_ref().value()
+    }
+    fn pure_mut<'a>(&'a mut self) -> Self::PureMut<'a>
+    where
+        T: 'a
+    {
+        self.as_mut().value()
+    }
+    fn pure_pin_ref<'a>(self: Pin<&'a Self>) -> Self::PurePinRef<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::as_pin_ref(self)
+    }
+    fn pure_pin_mut<'a>(self: Pin<&'a mut Self>) -> Self::PurePinMut<'a>
+    where
+        T: 'a
+    {
+        Maybe::<T>::as_pin_mut(self)
+    }
+    fn from_pure<U, P>(pure: P) -> Self::Kind<U, P>
+    where
+        P: PureMaybe<U>
+    {
+        pure
+    }
+}
+
+/// A [`Patch`](Patch) where whether or not it's absent is decided in the type, through `IS_PRESENT`.
+/// 
+/// A present patch is either null or set to a value at run-time, but an absent patch takes up no space and applying it
+/// does nothing at all.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// let mut target = Some(1);
+/// 
+/// StaticPatch::<i32, false>::absent().apply_to(&mut target);
+/// assert_eq!(target, Some(1));
+/// 
+/// StaticPatch::value(777).apply_to(&mut target);
+/// assert_eq!(target, Some(777));
+/// 
+/// StaticPatch::<i32, true>::null().apply_to(&mut target);
+/// assert_eq!(target, None);
+/// 
+/// assert_eq!(core::mem::size_of::<StaticPatch<i32, false>>(), 0);
+/// ```
+pub struct StaticPatch<T, const IS_PRESENT: bool>(OptCell<Option<T>, IS_PRESENT>);
+
+impl<T> StaticPatch<T, false>
+{
+    /// Creates a patch that leaves the value as it is.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let patch = StaticPatch::<i32, false>::absent();
+    /// 
+    /// assert!(patch.is_absent());
+    /// ```
+    pub const fn absent() -> Self
+    {
+        Self(OptCell::none())
+    }
+}
+impl<T> StaticPatch<T, true>
+{
+    /// Creates a patch that explicitly clears the value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let patch = StaticPatch::<i32, true>::null();
+    /// 
+    /// assert!(patch.is_null());
+    /// ```
+    pub const fn null() -> Self
+    {
+        Self(OptCell::some(None))
+    }
+    /// Creates a patch that sets the value to something.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let patch = StaticPatch::value(777);
+    /// 
+    /// assert!(patch.is_value());
+    /// ```
+    pub const fn value(value: T) -> Self
+    {
+        Self(OptCell::some(Some(value)))
+    }
+}
+impl<T, const IS_PRESENT: bool> StaticPatch<T, IS_PRESENT>
+{
+    /// Returns true if the patch leaves the value as it is. This is known at compile-time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(StaticPatch::<i32, false>::absent().is_absent());
+    /// assert!(!StaticPatch::<i32, true>::null().is_absent());
+    /// ```
+    pub const fn is_absent(&self) -> bool
+    {
+        !IS_PRESENT
+    }
+    /// Returns true if the patch explicitly clears the value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(!StaticPatch::<i32, false>::absent().is_null());
+    /// assert!(StaticPatch::<i32, true>::null().is_null());
+    /// assert!(!StaticPatch::value(777).is_null());
+    /// ```
+    pub fn is_null(&self) -> bool
+    {
+        self.as_patch().is_null()
+    }
+    /// Returns true if the patch sets the value to something.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(!StaticPatch::<i32, false>::absent().is_value());
+    /// assert!(!StaticPatch::<i32, true>::null().is_value());
+    /// assert!(StaticPatch::value(777).is_value());
+    /// ```
+    pub fn is_value(&self) -> bool
+    {
+        self.as_patch().is_value()
+    }
+
+    /// Borrows the patch as a run-time managed [`Patch`](Patch).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(StaticPatch::<i32, false>::absent().as_patch(), Patch::Absent);
+    /// assert_eq!(StaticPatch::value(777).as_patch(), Patch::Value(&777));
+    /// ```
+    pub fn as_patch(&self) -> Patch<&T>
+    {
+        Maybe::<Option<T>>::option_ref(&self.0)
+            .map(Option::as_ref)
+            .into()
+    }
+    /// Converts the patch into a run-time managed [`Patch`](Patch).
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(StaticPatch::<i32, false>::absent().into_patch(), Patch::Absent);
+    /// assert_eq!(StaticPatch::<i32, true>::null().into_patch(), Patch::Null);
+    /// assert_eq!(StaticPatch::value(777).into_patch(), Patch::Value(777));
+    /// ```
+    pub fn into_patch(self) -> Patch<T>
+    {
+        Maybe::<Option<T>>::option(self.0).into()
+    }
+
+    /// Applies the patch to an optional value. If the patch is absent, this does nothing.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut target = None;
+    /// 
+    /// StaticPatch::value(777).apply_to(&mut target);
+    /// assert_eq!(target, Some(777));
+    /// ```
+    pub fn apply_to<O>(self, target: &mut O)
+    where
+        O: Optional<Some = T>
+    {
+        if IS_PRESENT
+        {
+            self.into_patch().apply_to(target)
+        }
+    }
+}
+
+impl<T, const IS_PRESENT: bool> Clone for StaticPatch<T, IS_PRESENT>
+where
+    OptCell<Option<T>, IS_PRESENT>: Clone
+{
+    fn clone(&self) -> Self
+    {
+        Self(self.0.clone())
+    }
+}
+impl<T, const IS_PRESENT: bool> Copy for StaticPatch<T, IS_PRESENT>
+where
+    OptCell<Option<T>, IS_PRESENT>: Copy
+{
+
+}
+impl<T, const IS_PRESENT: bool> PartialEq for StaticPatch<T, IS_PRESENT>
+where
+    T: PartialEq
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.as_patch() == other.as_patch()
+    }
+}
+impl<T, const IS_PRESENT: bool> Eq for StaticPatch<T, IS_PRESENT>
+where
+    T: Eq
+{
+
+}
+impl<T, const IS_PRESENT: bool> Hash for StaticPatch<T, IS_PRESENT>
+where
+    T: Hash
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H)
+    {
+        self.as_patch().hash(state)
+    }
+}
+impl<T, const IS_PRESENT: bool> Debug for StaticPatch<T, IS_PRESENT>
+where
+    T: Debug
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        self.as_patch().fmt(f)
+    }
+}
+impl<T> Default for StaticPatch<T, false>
+{
+    fn default() -> Self
+    {
+        Self::absent()
+    }
+}
+
+impl<T, const IS_PRESENT: bool> From<StaticPatch<T, IS_PRESENT>> for Patch<T>
+{
+    fn from(value: StaticPatch<T, IS_PRESENT>) -> Self
+    {
+        value.into_patch()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn apply_to()
+    {
+        for (patch, expected) in [
+            (Patch::Absent, Some(1)),
+            (Patch::Null, None),
+            (Patch::Value(2), Some(2))
+        ]
+        {
+            let mut target = Some(1);
+            patch.apply_to(&mut target);
+            assert_eq!(target, expected);
+
+            let mut target = None;
+            patch.apply_to(&mut target);
+            assert_eq!(target, expected.filter(|_| patch.is_value()));
+
+            let nested: Option<Option<i32>> = patch.into();
+            assert_eq!(Patch::from(nested), patch);
+        }
+    }
+
+    #[test]
+    fn like_option()
+    {
+        for (patch, option) in [
+            (Patch::Absent, None),
+            (Patch::Null, None),
+            (Patch::Value(777), Some(777))
+        ]
+        {
+            assert_eq!(Maybe::<i32>::is_some(&patch), option.is_some());
+            assert_eq!(Maybe::<i32>::option_ref(&patch), option.as_ref());
+            assert_eq!(Maybe::<i32>::pure(patch), option);
+            assert_eq!(Maybe::<i32>::or(patch, 1), option.unwrap_or(1));
+        }
+    }
+
+    #[test]
+    fn static_patch()
+    {
+        let mut target = Some(1);
+
+        StaticPatch::<i32, false>::absent().apply_to(&mut target);
+        assert_eq!(target, Some(1));
+        StaticPatch::value(2).apply_to(&mut target);
+        assert_eq!(target, Some(2));
+        StaticPatch::<i32, true>::null().apply_to(&mut target);
+        assert_eq!(target, None);
+
+        assert_eq!(Patch::from(StaticPatch::<i32, false>::absent()), Patch::Absent);
+        assert_eq!(Patch::from(StaticPatch::<i32, true>::null()), Patch::Null);
+        assert_eq!(Patch::from(StaticPatch::value(2)), Patch::Value(2));
+    }
+}
\ No newline at end of file
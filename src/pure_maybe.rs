@@ -1,6 +1,12 @@
 use crate::{private, Maybe, NotVoid};
 
 /// A sealed trait for specifically either `T`, `()` or [`Option<T>`](core::option::Option).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a pure maybe of `{T}`",
+    label = "expected `{T}`, `()` or `Option<{T}>`",
+    note = "the pure maybes of `{T}` are `{T}`, `()` and `Option<{T}>`",
+    note = "other maybes can be turned into their pure form with `Maybe::pure`"
+)]
 pub trait PureMaybe<T>: Maybe<T> + private::PureMaybe<T>
 where
     T: ?Sized
@@ -1,6 +1,11 @@
 use crate::{NotVoid, PureMaybe, StaticMaybe};
 
 /// A sealed trait for specifically either `T`, `()`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a pure compile-time managed maybe of `{T}`",
+    label = "expected `{T}` or `()`",
+    note = "the pure compile-time managed maybes of `{T}` are `{T}` and `()`"
+)]
 pub trait PureStaticMaybe<T>: PureMaybe<T> + StaticMaybe<T>
 where
     T: ?Sized
@@ -0,0 +1,72 @@
+use crate::Maybe;
+
+/// A marker trait for putting requirements on the static guarantees of a [`Maybe`](crate::Maybe)-type in `where`-clauses.
+/// 
+/// It mirrors the constants of [`Maybe`](crate::Maybe), so they can be constrained with associated constant equality.
+/// 
+/// For an assertion with a custom error message, see [`assert_maybe`](crate::assert_maybe).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn f<O>(value: O) -> i32
+/// where
+///     O: Maybe<i32> + Require<i32, IS_ALWAYS_SOME = true>
+/// {
+///     value.unwrap()
+/// }
+/// 
+/// assert_eq!(f(777), 777);
+/// assert_eq!(f([777]), 777);
+/// assert_eq!(f(OptCell::some(777)), 777);
+/// ```
+/// 
+/// ```rust,compile_fail
+/// use option_trait::*;
+/// 
+/// fn f<O>(value: O) -> i32
+/// where
+///     O: Maybe<i32> + Require<i32, IS_ALWAYS_SOME = true>
+/// {
+///     value.unwrap()
+/// }
+/// 
+/// f(());
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not meet the requirements on a maybe of `{T}`",
+    label = "this maybe does not meet the requirements",
+    note = "`Require<{T}>` is implemented for every `Maybe<{T}>`",
+    note = "check the associated constants required in the `where`-clause against `Maybe::IS_MAYBE_SOME` and the other constants of `{Self}`"
+)]
+pub trait Require<T>
+where
+    T: ?Sized
+{
+    /// Equals [`Maybe::IS_MAYBE_SOME`](crate::Maybe::IS_MAYBE_SOME).
+    const IS_MAYBE_SOME: bool;
+    /// Equals [`Maybe::IS_MAYBE_NONE`](crate::Maybe::IS_MAYBE_NONE).
+    const IS_MAYBE_NONE: bool;
+    /// Equals [`Maybe::IS_NEVER_SOME`](crate::Maybe::IS_NEVER_SOME).
+    const IS_NEVER_SOME: bool;
+    /// Equals [`Maybe::IS_NEVER_NONE`](crate::Maybe::IS_NEVER_NONE).
+    const IS_NEVER_NONE: bool;
+    /// Equals [`Maybe::IS_ALWAYS_SOME`](crate::Maybe::IS_ALWAYS_SOME).
+    const IS_ALWAYS_SOME: bool;
+    /// Equals [`Maybe::IS_ALWAYS_NONE`](crate::Maybe::IS_ALWAYS_NONE).
+    const IS_ALWAYS_NONE: bool;
+}
+impl<M, T> Require<T> for M
+where
+    M: Maybe<T> + ?Sized,
+    T: ?Sized
+{
+    const IS_MAYBE_SOME: bool = <M as Maybe<T>>::IS_MAYBE_SOME;
+    const IS_MAYBE_NONE: bool = <M as Maybe<T>>::IS_MAYBE_NONE;
+    const IS_NEVER_SOME: bool = <M as Maybe<T>>::IS_NEVER_SOME;
+    const IS_NEVER_NONE: bool = <M as Maybe<T>>::IS_NEVER_NONE;
+    const IS_ALWAYS_SOME: bool = <M as Maybe<T>>::IS_ALWAYS_SOME;
+    const IS_ALWAYS_NONE: bool = <M as Maybe<T>>::IS_ALWAYS_NONE;
+}
\ No newline at end of file
@@ -0,0 +1,696 @@
+use core::{cmp::Ordering, fmt::Debug, hash::Hash};
+
+/// A run-time managed value of either type `L` or type `R`.
+/// 
+/// This is what a [`StaticEither`](StaticEither) turns into when it's converted into a run-time managed type, much like an [`OptCell`](crate::OptCell) turns into an [`Option`](core::option::Option).
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// let left = Either::<i32, &str>::Left(777);
+/// let right = Either::<i32, &str>::Right("Right");
+/// 
+/// assert_eq!(left.map_left(|value| value + 1), Either::Left(778));
+/// assert_eq!(right.either(|value| value.to_string(), |value| value.to_string()), "Right");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Either<L, R>
+{
+    Left(L),
+    Right(R)
+}
+
+impl<L, R> Either<L, R>
+{
+    /// Returns true if the value is on the left.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(Either::<i32, &str>::Left(777).is_left());
+    /// assert!(!Either::<i32, &str>::Right("Right").is_left());
+    /// ```
+    pub const fn is_left(&self) -> bool
+    {
+        matches!(self, Either::Left(_))
+    }
+    /// Returns true if the value is on the right.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(Either::<i32, &str>::Right("Right").is_right());
+    /// assert!(!Either::<i32, &str>::Left(777).is_right());
+    /// ```
+    pub const fn is_right(&self) -> bool
+    {
+        matches!(self, Either::Right(_))
+    }
+
+    /// Returns the left value, if it is on the left.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(Either::<i32, &str>::Left(777).left(), Some(777));
+    /// assert_eq!(Either::<i32, &str>::Right("Right").left(), None);
+    /// ```
+    pub fn left(self) -> Option<L>
+    {
+        match self
+        {
+            Either::Left(left) => Some(left),
+            Either::Right(_) => None
+        }
+    }
+    /// Returns the right value, if it is on the right.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(Either::<i32, &str>::Right("Right").right(), Some("Right"));
+    /// assert_eq!(Either::<i32, &str>::Left(777).right(), None);
+    /// ```
+    pub fn right(self) -> Option<R>
+    {
+        match self
+        {
+            Either::Left(_) => None,
+            Either::Right(right) => Some(right)
+        }
+    }
+
+    /// Borrows the value on either side.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = Either::<i32, String>::Right(String::from("Right"));
+    /// 
+    /// assert_eq!(either.as_ref(), Either::Right(&String::from("Right")));
+    /// ```
+    pub const fn as_ref(&self) -> Either<&L, &R>
+    {
+        match self
+        {
+            Either::Left(left) => Either::Left(left),
+            Either::Right(right) => Either::Right(right)
+        }
+    }
+    /// Mutably borrows the value on either side.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut either = Either::<i32, &str>::Left(777);
+    /// 
+    /// if let Either::Left(left) = either.as_mut()
+    /// {
+    ///     *left = 666;
+    /// }
+    /// 
+    /// assert_eq!(either, Either::Left(666));
+    /// ```
+    pub const fn as_mut(&mut self) -> Either<&mut L, &mut R>
+    {
+        match self
+        {
+            Either::Left(left) => Either::Left(left),
+            Either::Right(right) => Either::Right(right)
+        }
+    }
+
+    /// Maps the left value, if it is on the left.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(Either::<i32, &str>::Left(777).map_left(|value| value + 1), Either::Left(778));
+    /// assert_eq!(Either::<i32, &str>::Right("Right").map_left(|value| value + 1), Either::Right("Right"));
+    /// ```
+    pub fn map_left<U, F>(self, map: F) -> Either<U, R>
+    where
+        F: FnOnce(L) -> U
+    {
+        match self
+        {
+            Either::Left(left) => Either::Left(map(left)),
+            Either::Right(right) => Either::Right(right)
+        }
+    }
+    /// Maps the right value, if it is on the right.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(Either::<i32, &str>::Right("Right").map_right(str::len), Either::Right(5));
+    /// assert_eq!(Either::<i32, &str>::Left(777).map_right(str::len), Either::Left(777));
+    /// ```
+    pub fn map_right<U, F>(self, map: F) -> Either<L, U>
+    where
+        F: FnOnce(R) -> U
+    {
+        match self
+        {
+            Either::Left(left) => Either::Left(left),
+            Either::Right(right) => Either::Right(map(right))
+        }
+    }
+    /// Maps the value on either side into a common type.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let left = Either::<i32, &str>::Left(777);
+    /// let right = Either::<i32, &str>::Right("Right");
+    /// 
+    /// assert_eq!(left.either(|value| value.to_string(), String::from), "777");
+    /// assert_eq!(right.either(|value| value.to_string(), String::from), "Right");
+    /// ```
+    pub fn either<U, FL, FR>(self, left: FL, right: FR) -> U
+    where
+        FL: FnOnce(L) -> U,
+        FR: FnOnce(R) -> U
+    {
+        match self
+        {
+            Either::Left(value) => left(value),
+            Either::Right(value) => right(value)
+        }
+    }
+}
+
+/// A struct containing either a value of type `L` or a value of type `R`, depending on the constant expression `IS_LEFT`.
+/// 
+/// This is similar to [`Either`](Either), except which side the value is on is determined at compile-time. It takes up
+/// exactly as much space as the value it contains.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// let left = StaticEither::<i32, &str, true>::from_fns(|| 777, || "Right");
+/// let right = StaticEither::<i32, &str, false>::from_fns(|| 777, || "Right");
+/// 
+/// assert!(left.is_left());
+/// assert_eq!(left.unwrap_left(), 777);
+/// 
+/// assert!(right.is_right());
+/// assert_eq!(right.unwrap_right(), "Right");
+/// 
+/// assert_eq!(core::mem::size_of_val(&left), core::mem::size_of::<i32>());
+/// ```
+pub struct StaticEither<L, R, const IS_LEFT: bool>(<L as private::_Spec<R, IS_LEFT>>::Value);
+
+impl<L, R> StaticEither<L, R, true>
+{
+    /// Creates a [`StaticEither`](StaticEither) with a value on the left.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = StaticEither::<i32, &str, _>::left(777);
+    /// 
+    /// assert!(either.is_left());
+    /// assert_eq!(either.into_left(), 777);
+    /// ```
+    pub const fn left(value: L) -> Self
+    {
+        Self::assume_left(value)
+    }
+
+    /// Unwraps the left value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = StaticEither::<i32, &str, _>::left(777);
+    /// 
+    /// assert_eq!(either.into_left(), 777);
+    /// ```
+    pub const fn into_left(self) -> L
+    {
+        self.unwrap_left()
+    }
+}
+impl<L, R> StaticEither<L, R, false>
+{
+    /// Creates a [`StaticEither`](StaticEither) with a value on the right.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = StaticEither::<i32, &str, _>::right("Right");
+    /// 
+    /// assert!(either.is_right());
+    /// assert_eq!(either.into_right(), "Right");
+    /// ```
+    pub const fn right(value: R) -> Self
+    {
+        Self::assume_right(value)
+    }
+
+    /// Unwraps the right value.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = StaticEither::<i32, &str, _>::right("Right");
+    /// 
+    /// assert_eq!(either.into_right(), "Right");
+    /// ```
+    pub const fn into_right(self) -> R
+    {
+        self.unwrap_right()
+    }
+}
+impl<L, R, const IS_LEFT: bool> StaticEither<L, R, IS_LEFT>
+{
+    /// Creates a [`StaticEither`](StaticEither) from one of two functors.
+    /// 
+    /// Which functor is called depends entirely on the constant expression `IS_LEFT`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let left = StaticEither::<i32, &str, true>::from_fns(|| 777, || "Right");
+    /// let right = StaticEither::<i32, &str, false>::from_fns(|| 777, || "Right");
+    /// 
+    /// assert_eq!(left.unwrap_left(), 777);
+    /// assert_eq!(right.unwrap_right(), "Right");
+    /// ```
+    pub fn from_fns<FL, FR>(left: FL, right: FR) -> Self
+    where
+        FL: FnOnce() -> L,
+        FR: FnOnce() -> R
+    {
+        if !IS_LEFT
+        {
+            return Self::assume_right(right())
+        }
+        Self::assume_left(left())
+    }
+
+    /// Returns true if the value is on the left.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(StaticEither::<i32, &str, _>::left(777).is_left());
+    /// assert!(!StaticEither::<i32, &str, _>::right("Right").is_left());
+    /// ```
+    pub const fn is_left(&self) -> bool
+    {
+        IS_LEFT
+    }
+    /// Returns true if the value is on the right.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert!(StaticEither::<i32, &str, _>::right("Right").is_right());
+    /// assert!(!StaticEither::<i32, &str, _>::left(777).is_right());
+    /// ```
+    pub const fn is_right(&self) -> bool
+    {
+        !IS_LEFT
+    }
+
+    /// Borrows the value on either side.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = StaticEither::<String, i32, _>::left(String::from("Left"));
+    /// 
+    /// assert_eq!(either.as_ref().unwrap_left(), "Left");
+    /// ```
+    pub const fn as_ref(&self) -> StaticEither<&L, &R, IS_LEFT>
+    {
+        if !IS_LEFT
+        {
+            return StaticEither::assume_right(crate::assume_same_ref(&self.0))
+        }
+        StaticEither::assume_left(crate::assume_same_ref(&self.0))
+    }
+    /// Mutably borrows the value on either side.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut either = StaticEither::<i32, &str, _>::left(777);
+    /// 
+    /// *either.as_mut().unwrap_left() = 666;
+    /// 
+    /// assert_eq!(either.unwrap_left(), 666);
+    /// ```
+    pub const fn as_mut(&mut self) -> StaticEither<&mut L, &mut R, IS_LEFT>
+    {
+        if !IS_LEFT
+        {
+            return StaticEither::assume_right(crate::assume_same_mut(&mut self.0))
+        }
+        StaticEither::assume_left(crate::assume_same_mut(&mut self.0))
+    }
+
+    /// Unwraps the left value. If the value is on the right, it will result in an error.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = StaticEither::<i32, &str, _>::left(777);
+    /// 
+    /// assert_eq!(either.unwrap_left(), 777);
+    /// ```
+    pub const fn unwrap_left(self) -> L
+    {
+        if !IS_LEFT
+        {
+            Self::on_unwrap_left()
+        }
+        let x = crate::assume_same(unsafe {
+            core::ptr::read(&self.0)
+        });
+        core::mem::forget(self);
+        x
+    }
+    /// Unwraps the right value. If the value is on the left, it will result in an error.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let either = StaticEither::<i32, &str, _>::right("Right");
+    /// 
+    /// assert_eq!(either.unwrap_right(), "Right");
+    /// ```
+    pub const fn unwrap_right(self) -> R
+    {
+        if IS_LEFT
+        {
+            Self::on_unwrap_right()
+        }
+        let x = crate::assume_same(unsafe {
+            core::ptr::read(&self.0)
+        });
+        core::mem::forget(self);
+        x
+    }
+
+    /// Maps the left value, if it is on the left.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let left = StaticEither::<i32, &str, _>::left(777);
+    /// let right = StaticEither::<i32, &str, _>::right("Right");
+    /// 
+    /// assert_eq!(left.map_left(|value| value + 1).unwrap_left(), 778);
+    /// assert_eq!(right.map_left(|value| value + 1).unwrap_right(), "Right");
+    /// ```
+    pub fn map_left<U, F>(self, map: F) -> StaticEither<U, R, IS_LEFT>
+    where
+        F: FnOnce(L) -> U
+    {
+        if !IS_LEFT
+        {
+            return StaticEither::assume_right(self.unwrap_right())
+        }
+        StaticEither::assume_left(map(self.unwrap_left()))
+    }
+    /// Maps the right value, if it is on the right.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let left = StaticEither::<i32, &str, _>::left(777);
+    /// let right = StaticEither::<i32, &str, _>::right("Right");
+    /// 
+    /// assert_eq!(left.map_right(str::len).unwrap_left(), 777);
+    /// assert_eq!(right.map_right(str::len).unwrap_right(), 5);
+    /// ```
+    pub fn map_right<U, F>(self, map: F) -> StaticEither<L, U, IS_LEFT>
+    where
+        F: FnOnce(R) -> U
+    {
+        if !IS_LEFT
+        {
+            return StaticEither::assume_right(map(self.unwrap_right()))
+        }
+        StaticEither::assume_left(self.unwrap_left())
+    }
+    /// Maps the value on either side into a common type.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let left = StaticEither::<i32, &str, _>::left(777);
+    /// let right = StaticEither::<i32, &str, _>::right("Right");
+    /// 
+    /// assert_eq!(left.either(|value| value.to_string(), String::from), "777");
+    /// assert_eq!(right.either(|value| value.to_string(), String::from), "Right");
+    /// ```
+    pub fn either<U, FL, FR>(self, left: FL, right: FR) -> U
+    where
+        FL: FnOnce(L) -> U,
+        FR: FnOnce(R) -> U
+    {
+        if !IS_LEFT
+        {
+            return right(self.unwrap_right())
+        }
+        left(self.unwrap_left())
+    }
+
+    /// Converts the [`StaticEither`](StaticEither) into a run-time managed [`Either`](Either).
+    /// 
+    /// The conversion can only go one way, due to the nature of the container types being compile-time managed and run-time managed respectively.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let left = StaticEither::<i32, &str, _>::left(777);
+    /// let right = StaticEither::<i32, &str, _>::right("Right");
+    /// 
+    /// assert_eq!(left.into_either(), Either::Left(777));
+    /// assert_eq!(right.into_either(), Either::Right("Right"));
+    /// ```
+    pub const fn into_either(self) -> Either<L, R>
+    {
+        if !IS_LEFT
+        {
+            return Either::Right(self.unwrap_right())
+        }
+        Either::Left(self.unwrap_left())
+    }
+
+    const fn on_unwrap_left() -> !
+    {
+        panic!("called `StaticEither::unwrap_left()` on a `Right` value")
+    }
+    const fn on_unwrap_right() -> !
+    {
+        panic!("called `StaticEither::unwrap_right()` on a `Left` value")
+    }
+    const fn assume_left(value: L) -> Self
+    {
+        if !IS_LEFT
+        {
+            panic!("Tried to assume Left on Right.")
+        }
+        Self(crate::assume_same(value))
+    }
+    const fn assume_right(value: R) -> Self
+    {
+        if IS_LEFT
+        {
+            panic!("Tried to assume Right on Left.")
+        }
+        Self(crate::assume_same(value))
+    }
+}
+
+impl<L, R, const IS_LEFT: bool> Clone for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: Clone
+{
+    fn clone(&self) -> Self
+    {
+        StaticEither(self.0.clone())
+    }
+}
+impl<L, R, const IS_LEFT: bool> Copy for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: Copy
+{
+
+}
+impl<L, R, const IS_LEFT: bool> Hash for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: Hash
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H)
+    {
+        self.0.hash(state);
+    }
+}
+impl<L, R, const IS_LEFT: bool> PartialEq for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: PartialEq
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.0.eq(&other.0)
+    }
+}
+impl<L, R, const IS_LEFT: bool> Eq for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: Eq
+{
+
+}
+impl<L, R, const IS_LEFT: bool> PartialOrd for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: PartialOrd
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        self.0.partial_cmp(&other.0)
+    }
+}
+impl<L, R, const IS_LEFT: bool> Ord for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: Ord
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        self.0.cmp(&other.0)
+    }
+}
+impl<L, R, const IS_LEFT: bool> Debug for StaticEither<L, R, IS_LEFT>
+where
+    <L as private::_Spec<R, IS_LEFT>>::Value: Debug
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_tuple(if IS_LEFT {"Left"} else {"Right"})
+            .field(&self.0)
+            .finish()
+    }
+}
+impl<L, R, const IS_LEFT: bool> From<StaticEither<L, R, IS_LEFT>> for Either<L, R>
+{
+    fn from(value: StaticEither<L, R, IS_LEFT>) -> Self
+    {
+        value.into_either()
+    }
+}
+
+mod private
+{
+    pub trait _Spec<R, const IS_LEFT: bool>
+    {
+        type Value;
+    }
+    impl<L, R, const IS_LEFT: bool> _Spec<R, IS_LEFT> for L
+    {
+        default type Value = L;
+    }
+    impl<L, R> _Spec<R, true> for L
+    {
+        type Value = L;
+    }
+    impl<L, R> _Spec<R, false> for L
+    {
+        type Value = R;
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::{Either, StaticEither};
+
+    #[test]
+    fn it_works()
+    {
+        let left = StaticEither::<i32, &str, _>::left(777);
+        let right = StaticEither::<i32, &str, _>::right("Right");
+
+        assert_eq!(format!("{:?}", left), "Left(777)");
+        assert_eq!(format!("{:?}", right), "Right(\"Right\")");
+
+        assert_eq!(core::mem::size_of_val(&left), core::mem::size_of::<i32>());
+        assert_eq!(core::mem::size_of_val(&right), core::mem::size_of::<&str>());
+
+        assert_eq!(Either::from(left), Either::Left(777));
+        assert_eq!(Either::from(right), Either::Right("Right"));
+    }
+
+    #[test]
+    fn ordering()
+    {
+        let a = StaticEither::<i32, &str, _>::left(1);
+        let b = StaticEither::<i32, &str, _>::left(2);
+
+        assert!(a < b);
+        assert_eq!(a.cmp(&b), core::cmp::Ordering::Less);
+        assert_eq!(core::cmp::max(a, b), b);
+
+        let a = StaticEither::<i32, &str, _>::right("a");
+        let b = StaticEither::<i32, &str, _>::right("b");
+
+        assert!(a < b);
+        assert_eq!(a.partial_cmp(&b), Some(core::cmp::Ordering::Less));
+    }
+}
\ No newline at end of file
@@ -1,6 +1,46 @@
 use crate::{Maybe, NotVoid, PureStaticMaybe};
 
 /// A trait for [`Maybe`](crate::Maybe)-types that are compile-time managed.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn count<O>(_: O) -> usize
+/// where
+///     O: StaticMaybe<i32>
+/// {
+///     O::COUNT
+/// }
+/// 
+/// assert_eq!(count(777), 1);
+/// assert_eq!(count(()), 0);
+/// ```
+/// 
+/// An [`Option`](core::option::Option) is run-time managed, so it can't be used where a [`StaticMaybe`] is required.
+/// 
+/// ```rust,compile_fail,E0277
+/// use option_trait::*;
+/// 
+/// fn count<O>(_: O) -> usize
+/// where
+///     O: StaticMaybe<i32>
+/// {
+///     O::COUNT
+/// }
+/// 
+/// count(Some(777));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a compile-time managed maybe of `{T}`",
+    label = "expected a compile-time managed maybe of `{T}`",
+    note = "the supported compile-time managed maybes of `{T}` are `{T}`, `()`, `[{T}; 1]` and `[{T}; 0]`"
+)]
+#[cfg_attr(feature = "opt_cell", diagnostic::on_unimplemented(note = "`OptCell<{T}, IS_SOME>` is also a compile-time managed maybe of `{T}`"))]
+#[diagnostic::on_unimplemented(
+    note = "run-time managed maybes like `Option<{T}>` can't be used here"
+)]
 pub trait StaticMaybe<T>: Maybe<T>
 where
     T: ?Sized
@@ -9,6 +49,20 @@ where
     const IS_SOME: bool;
     /// Equals `true` if the [`Maybe`](crate::Maybe)-type does not contain a value.
     const IS_NONE: bool;
+    /// The number of values the [`Maybe`](crate::Maybe)-type contains. This is either `0` or `1`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// assert_eq!(<i32 as StaticMaybe<i32>>::COUNT, 1);
+    /// assert_eq!(<() as StaticMaybe<i32>>::COUNT, 0);
+    /// assert_eq!(<[i32; 1] as StaticMaybe<i32>>::COUNT, 1);
+    /// assert_eq!(<[i32; 0] as StaticMaybe<i32>>::COUNT, 0);
+    /// assert_eq!(<OptCell<i32, true> as StaticMaybe<i32>>::COUNT, 1);
+    /// ```
+    const COUNT: usize = Self::IS_SOME as usize;
     /// This kind of maybe if it contained a value.
     type Some: StaticMaybe<T> + ?Sized;
     /// This kind of maybe if it didn't contain a value.
@@ -138,6 +192,59 @@ where
     {
         self.unwrap_mut()
     }
+    /// Runs `func` on a reference to the inner value, if the maybe contains one.
+    /// 
+    /// Since whether or not there is a value is known at compile-time, this is a no-op for maybes that can't contain
+    /// one, and `func` is never called.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut seen = vec![];
+    /// 
+    /// StaticMaybe::<u64>::with_value(&7u64, |value| seen.push(*value));
+    /// StaticMaybe::<u64>::with_value(&(), |value| seen.push(*value));
+    /// 
+    /// assert_eq!(seen, [7]);
+    /// ```
+    fn with_value<F>(&self, func: F)
+    where
+        F: FnOnce(&T)
+    {
+        if let Some(value) = self.option_ref()
+        {
+            func(value)
+        }
+    }
+    /// Runs `func` on a mutable reference to the inner value, if the maybe contains one.
+    /// 
+    /// Since whether or not there is a value is known at compile-time, this is a no-op for maybes that can't contain
+    /// one, and `func` is never called.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let mut count = 0u64;
+    /// let mut nothing = ();
+    /// 
+    /// StaticMaybe::<u64>::with_value_mut(&mut count, |count| *count += 1);
+    /// StaticMaybe::<u64>::with_value_mut(&mut nothing, |count| *count += 1);
+    /// 
+    /// assert_eq!(count, 1);
+    /// ```
+    fn with_value_mut<F>(&mut self, func: F)
+    where
+        F: FnOnce(&mut T)
+    {
+        if let Some(value) = self.option_mut()
+        {
+            func(value)
+        }
+    }
 }
 impl<Some> /*const*/ StaticMaybe<Some> for Some
 where
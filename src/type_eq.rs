@@ -0,0 +1,198 @@
+use core::{fmt::Debug, marker::PhantomData};
+
+/// A proof that `A` and `B` are the same type.
+/// 
+/// A [`TypeEq`] can only be obtained if the types are equal, either with [`TypeEq::new`], or by checking with [`type_eq`].
+/// Once obtained, it can be used to safely cast values between the two types.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// fn as_i32<T>(value: T) -> Option<i32>
+/// where
+///     T: 'static
+/// {
+///     type_eq::<T, i32>().map(|eq| eq.cast(value))
+/// }
+/// 
+/// assert_eq!(as_i32(777), Some(777));
+/// assert_eq!(as_i32("777"), None);
+/// ```
+pub struct TypeEq<A, B>(PhantomData<fn(A) -> A>, PhantomData<fn(B) -> B>)
+where
+    A: ?Sized,
+    B: ?Sized;
+
+impl<T> TypeEq<T, T>
+where
+    T: ?Sized
+{
+    /// Returns the proof that a type is equal to itself.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let eq = TypeEq::<i32, i32>::new();
+    /// 
+    /// assert_eq!(eq.cast(777), 777);
+    /// ```
+    pub const fn new() -> Self
+    {
+        Self(PhantomData, PhantomData)
+    }
+}
+
+impl<A, B> TypeEq<A, B>
+where
+    A: ?Sized,
+    B: ?Sized
+{
+    /// Returns the proof only if `A` and `B` are the same type, disregarding lifetimes.
+    /// 
+    /// Only to be used where the lifetimes are known to be the same.
+    pub(crate) const fn if_same() -> Option<Self>
+    {
+        if crate::is_same_type::<A, B>()
+        {
+            Some(Self(PhantomData, PhantomData))
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Casts a value of type `A` into the same value of type `B`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let eq = type_eq::<u8, u8>().unwrap();
+    /// 
+    /// assert_eq!(eq.cast(7), 7u8);
+    /// ```
+    pub const fn cast(self, value: A) -> B
+    where
+        A: Sized,
+        B: Sized
+    {
+        unsafe { core::intrinsics::transmute_unchecked::<A, B>(value) }
+    }
+
+    /// Casts a reference to `A` into a reference to the same value of type `B`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let eq = type_eq::<str, str>().unwrap();
+    /// 
+    /// assert_eq!(eq.cast_ref("777"), "777");
+    /// ```
+    pub const fn cast_ref(self, value: &A) -> &B
+    {
+        unsafe { core::intrinsics::transmute_unchecked::<&A, &B>(value) }
+    }
+
+    /// Casts a mutable reference to `A` into a mutable reference to the same value of type `B`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let eq = type_eq::<[i32], [i32]>().unwrap();
+    /// let mut array = [1, 2, 3];
+    /// 
+    /// eq.cast_mut(&mut array[..])[0] = 777;
+    /// 
+    /// assert_eq!(array, [777, 2, 3]);
+    /// ```
+    pub const fn cast_mut(self, value: &mut A) -> &mut B
+    {
+        unsafe { core::intrinsics::transmute_unchecked::<&mut A, &mut B>(value) }
+    }
+
+    /// Turns the proof that `A` equals `B` into the proof that `B` equals `A`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use option_trait::*;
+    /// 
+    /// let eq = type_eq::<i32, i32>().unwrap().flip();
+    /// 
+    /// assert_eq!(eq.cast(777), 777);
+    /// ```
+    pub const fn flip(self) -> TypeEq<B, A>
+    {
+        TypeEq(PhantomData, PhantomData)
+    }
+}
+
+impl<A, B> Clone for TypeEq<A, B>
+where
+    A: ?Sized,
+    B: ?Sized
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+impl<A, B> Copy for TypeEq<A, B>
+where
+    A: ?Sized,
+    B: ?Sized
+{
+
+}
+impl<T> Default for TypeEq<T, T>
+where
+    T: ?Sized
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+impl<A, B> Debug for TypeEq<A, B>
+where
+    A: ?Sized,
+    B: ?Sized
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        write!(f, "TypeEq<{}, {}>", core::any::type_name::<A>(), core::any::type_name::<B>())
+    }
+}
+
+/// Returns the proof that `A` and `B` are the same type, or [`None`](core::option::Option::None) if they are not.
+/// 
+/// The types must be `'static`, since types that only differ in their lifetimes can't be told apart.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use option_trait::*;
+/// 
+/// const EQ: Option<TypeEq<u8, u8>> = type_eq::<u8, u8>();
+/// 
+/// assert!(EQ.is_some());
+/// assert!(type_eq::<u8, i8>().is_none());
+/// assert!(type_eq::<Option<&str>, Option<&str>>().is_some());
+/// ```
+pub const fn type_eq<A, B>() -> Option<TypeEq<A, B>>
+where
+    A: ?Sized + 'static,
+    B: ?Sized + 'static
+{
+    TypeEq::if_same()
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+// The expected diagnostics list the maybes of the default features.
+#![cfg(all(feature = "opt_cell", feature = "patch"))]
+
+#[test]
+fn ui()
+{
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
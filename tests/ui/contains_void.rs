@@ -0,0 +1,15 @@
+use option_trait::NotVoid;
+
+struct Wrapper(());
+
+fn f<T>()
+where
+    T: NotVoid
+{
+
+}
+
+fn main()
+{
+    f::<Wrapper>();
+}
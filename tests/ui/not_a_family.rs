@@ -0,0 +1,13 @@
+use option_trait::MaybeFamily;
+
+fn f<F>()
+where
+    F: MaybeFamily
+{
+
+}
+
+fn main()
+{
+    f::<Option<u8>>();
+}
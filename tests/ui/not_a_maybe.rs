@@ -0,0 +1,13 @@
+use option_trait::Maybe;
+
+fn f<O>(_: O)
+where
+    O: Maybe<i32>
+{
+
+}
+
+fn main()
+{
+    f("777");
+}
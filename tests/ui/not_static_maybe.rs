@@ -0,0 +1,13 @@
+use option_trait::StaticMaybe;
+
+fn f<O>(_: O)
+where
+    O: StaticMaybe<i32>
+{
+
+}
+
+fn main()
+{
+    f(Some(777));
+}